@@ -0,0 +1,135 @@
+//! Benchmarks every day's parser and solvers on the real input, or on the
+//! puzzle's example for days without one in `input/`.
+//!
+//! ```sh
+//! cargo bench --bench days -- day03
+//! ```
+
+use advent_of_code_2018::*;
+use advent_of_code_2018::solution::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Benchmarks parsing plus each of `parts`. Slow days get fewer samples.
+fn bench<S: Solution>(c: &mut Criterion, input: &str, parts: &[u8], samples: usize) {
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    group.sample_size(samples);
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input)).unwrap()));
+
+    let parsed = S::parse(input).unwrap();
+    for &part in parts {
+        group.bench_function(format!("part{}", part), |b| b.iter(|| match part {
+            1 => S::part1(black_box(&parsed)),
+            _ => S::part2(black_box(&parsed)),
+        }));
+    }
+
+    group.finish();
+}
+
+fn day01(c: &mut Criterion) {
+    bench::<day01::Day01>(c, include_str!("../input/day01.txt"), &[1, 2], 10);
+}
+
+fn day02(c: &mut Criterion) {
+    bench::<day02::Day02>(c, include_str!("../input/day02.txt"), &[1, 2], 100);
+}
+
+fn day03(c: &mut Criterion) {
+    bench::<day03::Day03>(c, include_str!("../input/day03.txt"), &[1, 2], 10);
+}
+
+fn day04(c: &mut Criterion) {
+    bench::<day04::Day04>(c, include_str!("../input/day04.txt"), &[1, 2], 100);
+}
+
+fn day05(c: &mut Criterion) {
+    bench::<day05::Day05>(c, include_str!("../input/day05.txt"), &[1, 2], 10);
+}
+
+fn day06(c: &mut Criterion) {
+    bench::<day06::Day06>(c, include_str!("../input/day06.txt"), &[1, 2], 10);
+}
+
+fn day07(c: &mut Criterion) {
+    bench::<day07::Day07>(c, include_str!("../input/day07.txt"), &[1], 100);
+}
+
+fn day08(c: &mut Criterion) {
+    bench::<day08::Day08>(c, include_str!("../input/day08_example.txt"), &[1, 2], 100);
+}
+
+fn day09(c: &mut Criterion) {
+    bench::<day09::Day09>(c, include_str!("../input/day09_example.txt"), &[1, 2], 10);
+}
+
+fn day10(c: &mut Criterion) {
+    bench::<day10::Day10>(c, include_str!("../input/day10.txt"), &[1, 2], 10);
+}
+
+fn day11(c: &mut Criterion) {
+    bench::<day11::Day11>(c, "7347", &[1, 2], 10);
+}
+
+// Part 2 never finishes
+fn day12(c: &mut Criterion) {
+    bench::<day12::Day12>(c, include_str!("../input/day12.txt"), &[1], 100);
+}
+
+fn day13(c: &mut Criterion) {
+    bench::<day13::Day13>(c, include_str!("../input/day13.txt"), &[1, 2], 10);
+}
+
+fn day14(c: &mut Criterion) {
+    bench::<day14::Day14>(c, include_str!("../input/day14_example.txt"), &[1, 2], 10);
+}
+
+fn day15(c: &mut Criterion) {
+    bench::<day15::Day15>(c, include_str!("../input/day15.txt"), &[1, 2], 10);
+}
+
+fn day16(c: &mut Criterion) {
+    bench::<day16::Day16>(c, include_str!("../input/day16_example.txt"), &[1], 100);
+}
+
+fn day17(c: &mut Criterion) {
+    bench::<day17::Day17>(c, include_str!("../input/day17_example.txt"), &[1, 2], 100);
+}
+
+fn day18(c: &mut Criterion) {
+    bench::<day18::Day18>(c, include_str!("../input/day18_example.txt"), &[1, 2], 10);
+}
+
+fn day19(c: &mut Criterion) {
+    bench::<day19::Day19>(c, include_str!("../input/day19_example.txt"), &[1, 2], 100);
+}
+
+fn day20(c: &mut Criterion) {
+    bench::<day20::Day20>(c, include_str!("../input/day20_example.txt"), &[1, 2], 100);
+}
+
+// Day 21 has no example to run
+
+fn day22(c: &mut Criterion) {
+    bench::<day22::Day22>(c, include_str!("../input/day22_example.txt"), &[1, 2], 10);
+}
+
+fn day23(c: &mut Criterion) {
+    bench::<day23::Day23>(c, include_str!("../input/day23_example2.txt"), &[1, 2], 100);
+}
+
+fn day24(c: &mut Criterion) {
+    bench::<day24::Day24>(c, include_str!("../input/day24_example.txt"), &[1, 2], 10);
+}
+
+fn day25(c: &mut Criterion) {
+    bench::<day25::Day25>(c, include_str!("../input/day25_example.txt"), &[1], 100);
+}
+
+criterion_group!(
+    days,
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10,
+    day11, day12, day13, day14, day15, day16, day17, day18, day19, day20,
+    day22, day23, day24, day25
+);
+criterion_main!(days);
@@ -0,0 +1,612 @@
+use advent_of_code_2018::batch::{self, Outcome};
+use advent_of_code_2018::budget;
+use advent_of_code_2018::gen;
+use advent_of_code_2018::input;
+use advent_of_code_2018::output::{Format, Object};
+use advent_of_code_2018::reference;
+use advent_of_code_2018::registry::{self, Day};
+use advent_of_code_2018::solution::Source;
+use advent_of_code_2018::verify::{self, Status};
+use advent_of_code_2018::visualize::{self, Backend, Gif, Pngs, Terminal, Text};
+
+use std::env::args;
+use std::fs::{self, File};
+use std::io::{BufWriter, Read, stderr, stdin};
+use std::panic;
+use std::path::PathBuf;
+use std::process::exit;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// What --visualize can show a day on.
+const VISUALIZE: &[&str] = &["terminal", "text", "gif", "png"];
+
+const USAGE: &str = "\
+Usage:
+    aoc list
+    aoc run <day> [--part <1|2>] [--input <file> | --example] [--time]
+                  [--timeout <seconds>] [--format <text|json>] [<value>]
+                  [--visualize <terminal|text|gif|png> [--fps <n>] [--step]
+                   [--output <path>]]
+    aoc run --all [--example] [--timeout <seconds>] [--format <text|json>]
+    aoc check <day> [<file>]
+    aoc gen <day> [--seed <n>]
+    aoc diff <day> [--input <file> | --seeds <n>] [--timeout <seconds>]
+    aoc verify [--input <dir>] [--answers <dir>] [--timeout <seconds>]
+               [--format <text|json>]
+
+Puzzle input is read from input/dayNN.txt, or input/dayNN_example.txt with
+--example. Set AOC_INPUT_DIR to use another directory. --input reads a file
+instead, looking in the input directory too (`-` for stdin), and days that
+take a single number also take it as <value>. --time reports how long parsing
+and each part took. --timeout gives up on a day that runs too long, saying
+how many steps it got through.
+
+--visualize shows days 10, 13 and 15 running, a frame per step: as an
+animation in the terminal, --fps frames a second (10 unless given), or
+starting paused with --step; as plain text on stderr, or in the --output
+file; or as an animated GIF (dayNN.gif unless given) or a directory of PNGs
+(dayNN-frames). In the terminal, enter pauses and steps, p plays on and q
+stops watching.
+
+run --all runs every day at once on its input, or its example, and prints a
+table of the answers and timings. A part that fails, panics or runs past the
+timeout (10 seconds unless given) is reported without stopping the others.
+
+check reads a puzzle file (the day's input file unless given) and reports
+every line that doesn't fit the day's format, without solving anything.
+
+gen prints a random input for a day, shaped like the real ones. The same
+seed always gives the same input; without one, the seed used goes to stderr.
+
+diff runs a day's solver and its simple reference version over an input, or
+over the first n generated inputs (10 unless given), and stops at the first
+disagreement, shrinking the input down to as little as still shows it.
+
+verify (or --verify) runs every day against every file in the input directory
+and compares the results with answers/dayNN.toml.
+
+--format json prints one JSON object per line instead: for run, the day,
+part, answer, elapsed_ms and input of every part solved, plus the status and
+error with --all.";
+
+fn main() {
+    if let Err(e) = run(args().skip(1).collect()) {
+        eprintln!("Error: {}", e);
+        exit(1);
+    }
+}
+
+fn run(args: Vec<String>) -> Result<()> {
+    match args.first().map(String::as_str) {
+        Some("list") => {
+            list();
+            Ok(())
+        },
+        Some("run") => {
+            let options = Options::parse(&args[1..])?;
+            if options.all { run_all(&options) } else { run_day(&options) }
+        },
+        Some("check") => check(&args[1..]),
+        Some("gen") => generate(&args[1..]),
+        Some("diff") => diff(&args[1..]),
+        Some("verify") | Some("--verify") => verify(&VerifyOptions::parse(&args[1..])?),
+        Some("help") | Some("-h") | Some("--help") => {
+            println!("{}", USAGE);
+            Ok(())
+        },
+        _ => Err(USAGE.into()),
+    }
+}
+
+fn list() {
+    for day in registry::DAYS {
+        println!("Day {:02}: {}{}",
+            day.day,
+            day.title,
+            if day.part2.is_none() { " (part 1 only)" } else { "" },
+        );
+    }
+}
+
+#[derive(Debug)]
+struct Options {
+    day: u8,
+    part: Option<u8>,
+    input: Option<String>,
+    value: Option<String>,
+    time: bool,
+    example: bool,
+    format: Format,
+    all: bool,
+    /// No limit for a single day unless given
+    timeout: Option<Duration>,
+    visualize: Option<String>,
+    fps: f64,
+    step: bool,
+    output: Option<PathBuf>,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Options> {
+        let mut options = Options {
+            day: 0,
+            part: None,
+            input: None,
+            value: None,
+            time: false,
+            example: false,
+            format: Format::Text,
+            all: false,
+            timeout: None,
+            visualize: None,
+            fps: 10.0,
+            step: false,
+            output: None,
+        };
+        let mut day = None;
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    let part = args.next().ok_or("--part needs a value")?;
+                    options.part = Some(part.parse()?);
+                },
+                "--input" | "-i" => {
+                    let path = args.next().ok_or("--input needs a value")?;
+                    options.input = Some(path.clone());
+                },
+                "--time" | "-t" => options.time = true,
+                "--example" | "-e" => options.example = true,
+                "--format" | "-f" => {
+                    options.format = args.next().ok_or("--format needs `text` or `json`")?.parse()?;
+                },
+                "--all" | "-a" => options.all = true,
+                "--timeout" => {
+                    let secs = args.next().ok_or("--timeout needs a number of seconds")?;
+                    options.timeout = Some(Duration::from_secs(secs.parse()?));
+                },
+                "--visualize" | "-v" => {
+                    let style = args.next().ok_or("--visualize needs `terminal`, `text`, `gif` or `png`")?;
+                    if !VISUALIZE.contains(&style.as_str()) {
+                        return Err(format!("--visualize needs `terminal`, `text`, `gif` or `png`, not `{}`", style).into());
+                    }
+                    options.visualize = Some(style.clone());
+                },
+                "--fps" => {
+                    options.fps = args.next().ok_or("--fps needs a number of frames a second")?.parse()?;
+                    if options.fps.is_nan() || options.fps <= 0.0 {
+                        return Err("--fps needs more than 0 frames a second".into());
+                    }
+                },
+                "--step" => options.step = true,
+                "--output" | "-o" => {
+                    options.output = Some(args.next().ok_or("--output needs a path")?.into());
+                },
+                _ if day.is_none() => day = Some(arg.parse()?),
+                _ if options.value.is_none() => options.value = Some(arg.clone()),
+                _ => return Err(format!("Unexpected argument: {}", arg).into()),
+            }
+        }
+
+        if options.all {
+            if day.is_some() || options.part.is_some() || options.input.is_some() {
+                return Err("--all runs both parts of every day; drop the day, --part and --input".into());
+            }
+            if options.visualize.is_some() {
+                return Err("--visualize shows one day at a time; give it a day instead of --all".into());
+            }
+        } else {
+            options.day = day.ok_or("Which day?")?;
+        }
+
+        if options.visualize.is_none() && (options.step || options.output.is_some()) {
+            return Err("--step and --output go with --visualize".into());
+        }
+
+        Ok(options)
+    }
+
+    /// Where to show the day running, if anywhere.
+    fn backend(&self, day: &Day) -> Result<Option<Box<dyn Backend>>> {
+        let output = |default: String| self.output.clone().unwrap_or_else(|| PathBuf::from(default));
+
+        let backend: Box<dyn Backend> = match self.visualize.as_deref() {
+            None => return Ok(None),
+            Some("terminal") => Box::new(Terminal::new(self.fps, self.step)),
+            Some("text") => match &self.output {
+                Some(path) => Box::new(Text(BufWriter::new(File::create(path)?))),
+                None => Box::new(Text(stderr())),
+            },
+            Some("gif") => Box::new(Gif::new(output(format!("day{:02}.gif", day.day)), self.fps)),
+            Some(_) => Box::new(Pngs::new(output(format!("day{:02}-frames", day.day)))),
+        };
+
+        Ok(Some(backend))
+    }
+
+    /// The puzzle input, and where it came from.
+    fn read_input(&self, day: &Day) -> Result<(String, String)> {
+        match (&self.input, &self.value) {
+            (Some(_), Some(_)) => Err("Give either --input or a value, not both".into()),
+            (_, _) if self.example && (self.input.is_some() || self.value.is_some()) =>
+                Err("--example reads the day's example file; drop --input and the value".into()),
+            (Some(path), None) if path == "-" => {
+                if day.source == Source::Number {
+                    eprintln!("Reading day {} input number from stdin...", day.day);
+                }
+                let mut input = String::new();
+                stdin().read_to_string(&mut input)?;
+                Ok((input, "-".to_string()))
+            },
+            (Some(path), None) => {
+                let path = input::resolve(path);
+                Ok((fs::read_to_string(&path)?, path.display().to_string()))
+            },
+            (None, Some(value)) => Ok((value.clone(), value.clone())),
+            (None, None) => {
+                let path = input::path(day.day, self.example);
+                match fs::read_to_string(&path) {
+                    Ok(input) => Ok((input, path.display().to_string())),
+                    Err(e) => Err(format!("Couldn't read {}: {}", path.display(), e).into()),
+                }
+            },
+        }
+    }
+}
+
+fn run_day(options: &Options) -> Result<()> {
+    let day = registry::get(options.day)
+        .ok_or_else(|| format!("Day {} is not implemented", options.day))?;
+
+    let parts = match options.part {
+        Some(part) if day.part(part).is_none() =>
+            return Err(format!("Day {} has no part {}", day.day, part).into()),
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let (input, source) = options.read_input(day)?;
+    let solve = || match options.timeout {
+        Some(timeout) => budget::limit(timeout, || (day.timed)(&input, &parts)),
+        None => (day.timed)(&input, &parts),
+    };
+    let run = match options.backend(day)? {
+        Some(backend) => visualize::show(backend, solve)?,
+        None => solve()?,
+    };
+
+    if options.format == Format::Json {
+        for (part, answer, elapsed) in &run.parts {
+            let record = Object::new()
+                .number("day", day.day)
+                .number("part", part)
+                .string("answer", answer.to_string().trim_end())
+                .elapsed("elapsed_ms", *elapsed)
+                .string("input", &source);
+            println!("{}", record);
+        }
+        return Ok(());
+    }
+
+    for (part, answer, _) in &run.parts {
+        let answer = answer.to_string();
+        let answer = answer.trim_end();
+        if answer.contains('\n') {
+            println!("Day {}, Part {}:\n{}", day.day, part, answer);
+        } else {
+            println!("Day {}, Part {}: {}", day.day, part, answer);
+        }
+    }
+
+    if options.time {
+        println!("\nParse:  {:?}", run.parse);
+        for (part, _, time) in &run.parts {
+            println!("Part {}: {:?}", part, time);
+        }
+        let total = run.parse + run.parts.iter().map(|(_, _, time)| *time).sum::<Duration>();
+        println!("Total:  {:?}", total);
+    }
+
+    Ok(())
+}
+
+fn run_all(options: &Options) -> Result<()> {
+    let reports = batch::run_all(options.example, options.timeout.unwrap_or(Duration::from_secs(10)));
+    let failed = reports.iter().filter(|r| !r.outcome.is_ok()).count();
+
+    if options.format == Format::Json {
+        for report in &reports {
+            let (answer, elapsed, error) = match &report.outcome {
+                Outcome::Solved { answer, elapsed, .. } => (Some(answer.to_string()), Some(*elapsed), None),
+                Outcome::Error(e) | Outcome::Panicked(e) => (None, None, Some(e.clone())),
+                Outcome::Timeout { after, steps } => (None, None, Some(timed_out(*after, *steps))),
+                Outcome::NoInput => (None, None, None),
+            };
+            let mut record = Object::new()
+                .number("day", report.day)
+                .number("part", report.part)
+                .string("status", status(&report.outcome))
+                .maybe("answer", answer.as_deref().map(str::trim_end));
+            record = match elapsed {
+                Some(elapsed) => record.elapsed("elapsed_ms", elapsed),
+                None => record.maybe("elapsed_ms", None),
+            };
+            let record = record
+                .string("input", &report.input)
+                .maybe("error", error.as_deref());
+            println!("{}", record);
+        }
+    } else {
+        print_summary(&reports);
+    }
+
+    if failed > 0 {
+        exit(1);
+    }
+
+    Ok(())
+}
+
+fn status(outcome: &Outcome) -> &'static str {
+    match outcome {
+        Outcome::Solved { .. } => "solved",
+        Outcome::Error(_) => "error",
+        Outcome::Panicked(_) => "panicked",
+        Outcome::Timeout { .. } => "timeout",
+        Outcome::NoInput => "no input",
+    }
+}
+
+fn timed_out(after: Duration, steps: Option<u64>) -> String {
+    match steps {
+        Some(steps) => format!("timed out after {} steps ({:?})", steps, after),
+        None => format!("timed out after {:?}", after),
+    }
+}
+
+fn print_summary(reports: &[batch::Report]) {
+    let width = reports.iter().map(|r| r.title.len()).max().unwrap_or(0);
+    println!("Day  {:<width$}  Part  {:>12}  Answer", "Title", "Time", width = width);
+
+    for report in reports {
+        print!("{:>3}  {:<width$}  {:>4}  ", report.day, report.title, report.part, width = width);
+        match &report.outcome {
+            Outcome::Solved { answer, parse, elapsed } => {
+                let answer = answer.to_string();
+                let answer = answer.trim_end();
+                let answer = match answer.lines().count() {
+                    1 => answer.to_string(),
+                    lines => format!("({} lines; see `aoc run {}`)", lines, report.day),
+                };
+                println!("{:>12}  {}", format!("{:.2?}", *parse + *elapsed), answer);
+            },
+            Outcome::Error(e) => println!("{:>12}  ERROR: {}", "-", e),
+            Outcome::Panicked(e) => println!("{:>12}  PANICKED: {}", "-", e),
+            Outcome::Timeout { after, steps } => println!("{:>12}  TIMEOUT: {}", "-", timed_out(*after, *steps)),
+            Outcome::NoInput => println!("{:>12}  no input", "-"),
+        }
+    }
+
+    let count = |f: fn(&Outcome) -> bool| reports.iter().filter(|r| f(&r.outcome)).count();
+    let solved = count(|o| matches!(o, Outcome::Solved { .. }));
+    let missing = count(|o| *o == Outcome::NoInput);
+    let failed = count(|o| !o.is_ok());
+    let total = reports.iter()
+        .filter_map(|r| match r.outcome {
+            Outcome::Solved { parse, elapsed, .. } => Some(parse + elapsed),
+            _ => None,
+        })
+        .sum::<Duration>();
+    println!("\n{} solved in {:.2?}, {} failed, {} without input", solved, total, failed, missing);
+}
+
+fn check(args: &[String]) -> Result<()> {
+    let (day, path) = match args {
+        [day] => {
+            let day = day.parse()?;
+            (day, input::path(day, false))
+        },
+        [day, file] => (day.parse()?, input::resolve(file)),
+        _ => return Err("check needs a day, and optionally a file".into()),
+    };
+
+    let day = registry::get(day).ok_or_else(|| format!("Day {} is not implemented", day))?;
+    let input = fs::read_to_string(&path)
+        .map_err(|e| format!("Couldn't read {}: {}", path.display(), e))?;
+
+    let errors = (day.check)(&input);
+    for error in &errors {
+        println!("{}: {}", path.display(), error);
+    }
+
+    if errors.is_empty() {
+        println!("{}: ok", path.display());
+        Ok(())
+    } else {
+        println!("\n{} problem{} found", errors.len(), if errors.len() == 1 { "" } else { "s" });
+        exit(1);
+    }
+}
+
+fn generate(args: &[String]) -> Result<()> {
+    let (day, seed) = match args {
+        [day] => {
+            let seed = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+            eprintln!("Seed: {}", seed);
+            (day, seed)
+        },
+        [day, flag, seed] if flag == "--seed" || flag == "-s" => (day, seed.parse()?),
+        _ => return Err("gen needs a day, and optionally --seed <n>".into()),
+    };
+
+    let day = day.parse()?;
+    let input = gen::input(day, seed).ok_or_else(|| {
+        let days = gen::DAYS.iter().map(u8::to_string).collect::<Vec<String>>();
+        format!("There's no generator for day {}; try one of {}", day, days.join(", "))
+    })?;
+
+    print!("{}", input);
+    Ok(())
+}
+
+fn diff(args: &[String]) -> Result<()> {
+    let mut day = None;
+    let mut input = None;
+    let mut seeds = 10;
+    let mut timeout = Duration::from_secs(10);
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => input = Some(args.next().ok_or("--input needs a value")?.clone()),
+            "--seeds" | "-s" => seeds = args.next().ok_or("--seeds needs a number")?.parse()?,
+            "--timeout" => {
+                let secs = args.next().ok_or("--timeout needs a number of seconds")?;
+                timeout = Duration::from_secs(secs.parse()?);
+            },
+            _ if day.is_none() => day = Some(arg.parse::<u8>()?),
+            _ => return Err(format!("Unexpected argument: {}", arg).into()),
+        }
+    }
+
+    let day = day.ok_or("Which day?")?;
+    if reference::get(day).is_none() {
+        let days = reference::DAYS.iter().map(|d| d.day.to_string()).collect::<Vec<String>>();
+        return Err(format!("Day {} has no reference version; try one of {}", day, days.join(", ")).into());
+    }
+
+    let inputs = match input {
+        Some(path) => {
+            let path = input::resolve(&path);
+            vec![(path.display().to_string(), fs::read_to_string(&path)?)]
+        },
+        None => (0..seeds)
+            .map(|seed| {
+                let input = gen::input(day, seed)
+                    .ok_or_else(|| format!("There's no generator for day {}; give it an --input", day))?;
+                Ok((format!("seed {}", seed), input))
+            })
+            .collect::<Result<Vec<(String, String)>>>()?,
+    };
+
+    // Shrinking runs the solvers over plenty of broken inputs; one panic
+    // message is enough, and it goes in the report
+    panic::set_hook(Box::new(|_| ()));
+
+    for (source, input) in &inputs {
+        if let Some(d) = reference::differ(day, input, timeout) {
+            println!("Day {}, part {} disagrees on {}. The smallest input that still does:\n", day, d.part, source);
+            println!("{}", d.input.trim_end());
+            println!("\nSolver:    {}\nReference: {}", d.solver, d.reference);
+            exit(1);
+        }
+    }
+
+    println!("Day {} agrees with its reference on {} input{}", day, inputs.len(), if inputs.len() == 1 { "" } else { "s" });
+    Ok(())
+}
+
+#[derive(Debug)]
+struct VerifyOptions {
+    input: PathBuf,
+    answers: PathBuf,
+    timeout: Duration,
+    format: Format,
+}
+
+impl VerifyOptions {
+    fn parse(args: &[String]) -> Result<VerifyOptions> {
+        let mut options = VerifyOptions {
+            input: input::dir(),
+            answers: PathBuf::from("answers"),
+            timeout: Duration::from_secs(10),
+            format: Format::Text,
+        };
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    options.input = args.next().ok_or("--input needs a directory")?.into();
+                },
+                "--answers" | "-a" => {
+                    options.answers = args.next().ok_or("--answers needs a directory")?.into();
+                },
+                "--timeout" | "-t" => {
+                    let secs = args.next().ok_or("--timeout needs a number of seconds")?;
+                    options.timeout = Duration::from_secs(secs.parse()?);
+                },
+                "--format" | "-f" => {
+                    options.format = args.next().ok_or("--format needs `text` or `json`")?.parse()?;
+                },
+                _ => return Err(format!("Unexpected argument: {}", arg).into()),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+fn verify(options: &VerifyOptions) -> Result<()> {
+    let checks = verify::verify(&options.input, &options.answers, options.timeout)?;
+    let failed = checks.iter().filter(|c| !c.status.is_ok()).count();
+
+    if options.format == Format::Json {
+        for check in &checks {
+            let (answer, error) = match &check.status {
+                Status::Fail(answer) => (Some(answer.trim_end()), None),
+                Status::Error(e) => (None, Some(e.as_str())),
+                _ => (None, None),
+            };
+            let record = Object::new()
+                .number("day", check.day)
+                .number("part", check.part)
+                .string("input", &check.input)
+                .string("status", &check.status.to_string().to_lowercase())
+                .maybe("expected", check.expected.as_deref().map(str::trim_end))
+                .maybe("answer", answer)
+                .maybe("error", error);
+            println!("{}", record);
+        }
+    } else {
+        print_table(&checks);
+    }
+
+    if failed > 0 {
+        exit(1);
+    }
+
+    Ok(())
+}
+
+fn print_table(checks: &[verify::Check]) {
+    let width = checks.iter().map(|c| c.input.len()).max().unwrap_or(0).max("Input".len());
+    println!("Day  {:<width$}  Part  Status", "Input", width = width);
+
+    for check in checks {
+        print!("{:>3}  {:<width$}  {:>4}  {}", check.day, check.input, check.part, check.status, width = width);
+        match &check.status {
+            Status::Fail(answer) => {
+                let expected = check.expected.as_deref().unwrap_or_default();
+                print!(": expected {}, got {}", one_line(expected), one_line(answer));
+            },
+            Status::Error(e) => print!(": {}", e),
+            _ => (),
+        }
+        println!();
+    }
+
+    let count = |f: fn(&Status) -> bool| checks.iter().filter(|c| f(&c.status)).count();
+    let passed = count(|s| *s == Status::Pass);
+    let missing = count(|s| *s == Status::Missing);
+    let failed = count(|s| !s.is_ok());
+    println!("\n{} passed, {} failed, {} missing", passed, failed, missing);
+}
+
+/// Keeps multi-line answers on one row of the table.
+fn one_line(answer: &str) -> String {
+    answer.trim_end().replace('\n', "\\n")
+}
@@ -1,37 +1,21 @@
-use std::io::{Read, stdin};
+use advent_of_code_2018::day01::Calibrator;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
 
-pub fn main() {
-    let mut input = String::new();
-    stdin().read_to_string(&mut input).expect("Problem reading from stdin!");
+fn main() -> Result<()> {
+    let mut calibrator = Calibrator::new();
+    calibrator.read(input::reader(1)?)?;
 
-    let vec_isize = input.lines()
-        .filter_map(|line| line.parse().ok())
-        .collect();
-
-    let part1 = part_1(&vec_isize);
-    println!("Part 1: {}", part1);
-
-    let part2 = part_2(&vec_isize);
-    println!("Part 2: {}", part2);
-}
-
-pub fn part_1(vec_isize: &Vec<isize>) -> isize {
-    vec_isize.iter().sum()
-}
+    for error in calibrator.rejected() {
+        eprintln!("Skipping {}", error);
+    }
 
-pub fn part_2(vec_isize: &Vec<isize>) -> isize {
-    let mut new_vec: Vec<isize> = Vec::new();
-    let mut val: isize = 0;
+    println!("Part 1: {}", calibrator.frequency());
 
-    while !new_vec.contains(&val) {
-        for i in vec_isize {
-            if new_vec.contains(&val) {
-                break;
-            }
-            new_vec.push(val);
-            val += i;
-        }
+    match calibrator.go_round() {
+        Some(part2) => println!("Part 2: {}", part2),
+        None => println!("Part 2: no frequency is ever reached twice"),
     }
 
-    val
+    Ok(())
 }
@@ -0,0 +1,14 @@
+use advent_of_code_2018::day08;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
+
+fn main() -> Result<()> {
+    let input = input::read(8)?;
+
+    let parsed = day08::parse(&input)?;
+
+    println!("Part 1: {}", day08::part1(&parsed));
+    println!("Part 2: {}", day08::part2(&parsed));
+
+    Ok(())
+}
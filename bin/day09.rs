@@ -0,0 +1,14 @@
+use advent_of_code_2018::day09;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
+
+fn main() -> Result<()> {
+    let input = input::read(9)?;
+
+    let parsed = day09::parse(&input)?;
+
+    println!("Part 1: {}", day09::part1(&parsed));
+    println!("Part 2: {}", day09::part2(&parsed));
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use advent_of_code_2018::day14;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
+
+fn main() -> Result<()> {
+    let input = input::read(14)?;
+
+    let parsed = day14::parse(&input)?;
+
+    match day14::part1(&parsed) {
+        Some(scores) => println!("Part 1: {}", scores),
+        None => println!("Part 1: more recipes than the board can hold"),
+    }
+    match day14::part2(&parsed) {
+        Some(recipes) => println!("Part 2: {}", recipes),
+        None => println!("Part 2: the scores never show up"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use advent_of_code_2018::day16;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
+
+fn main() -> Result<()> {
+    let input = input::read(16)?;
+
+    let manual = day16::parse(&input)?;
+
+    println!("Part 1: {}", day16::part1(&manual));
+
+    match day16::part2(&manual) {
+        Some(r0) => println!("Part 2: {}", r0),
+        None => println!("Part 2: the samples don't pin down every opcode"),
+    }
+
+    Ok(())
+}
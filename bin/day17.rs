@@ -0,0 +1,14 @@
+use advent_of_code_2018::day17;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
+
+fn main() -> Result<()> {
+    let input = input::read(17)?;
+
+    let parsed = day17::parse(&input)?;
+
+    println!("Part 1: {}", day17::part1(&parsed));
+    println!("Part 2: {}", day17::part2(&parsed));
+
+    Ok(())
+}
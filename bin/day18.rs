@@ -0,0 +1,14 @@
+use advent_of_code_2018::day18;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
+
+fn main() -> Result<()> {
+    let input = input::read(18)?;
+
+    let parsed = day18::parse(&input)?;
+
+    println!("Part 1: {}", day18::part1(&parsed));
+    println!("Part 2: {}", day18::part2(&parsed));
+
+    Ok(())
+}
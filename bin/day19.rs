@@ -0,0 +1,14 @@
+use advent_of_code_2018::day19;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
+
+fn main() -> Result<()> {
+    let input = input::read(19)?;
+
+    let parsed = day19::parse(&input)?;
+
+    println!("Part 1: {}", day19::part1(&parsed));
+    println!("Part 2: {}", day19::part2(&parsed));
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use advent_of_code_2018::day20;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
+
+fn main() -> Result<()> {
+    let input = input::read(20)?;
+
+    let parsed = day20::parse(&input)?;
+
+    println!("Part 1: {}", day20::part1(&parsed));
+    println!("Part 2: {}", day20::part2(&parsed));
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use advent_of_code_2018::day21;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
+
+fn main() -> Result<()> {
+    let input = input::read(21)?;
+
+    let parsed = day21::parse(&input)?;
+
+    for (part, answer) in [(1, day21::part1(&parsed)), (2, day21::part2(&parsed))].iter() {
+        match answer {
+            Some(answer) => println!("Part {}: {}", part, answer),
+            None => println!("Part {}: unsolved", part),
+        }
+    }
+
+    Ok(())
+}
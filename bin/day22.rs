@@ -0,0 +1,17 @@
+use advent_of_code_2018::day22;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
+
+fn main() -> Result<()> {
+    let input = input::read(22)?;
+
+    let parsed = day22::parse(&input)?;
+
+    println!("Part 1: {}", day22::part1(&parsed));
+    match day22::part2(&parsed) {
+        Some(minutes) => println!("Part 2: {}", minutes),
+        None => println!("Part 2: the torch isn't allowed at the target"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use advent_of_code_2018::day23;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
+
+fn main() -> Result<()> {
+    let input = input::read(23)?;
+
+    let parsed = day23::parse(&input)?;
+
+    println!("Part 1: {}", day23::part1(&parsed));
+    println!("Part 2: {}", day23::part2(&parsed));
+
+    Ok(())
+}
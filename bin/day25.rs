@@ -0,0 +1,13 @@
+use advent_of_code_2018::day25;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::input;
+
+fn main() -> Result<()> {
+    let input = input::read(25)?;
+
+    let points = day25::parse(&input)?;
+
+    println!("Part 1: {}", day25::part1(&points));
+
+    Ok(())
+}
@@ -0,0 +1,214 @@
+//! Expected answers, kept in `answers/dayNN.toml`.
+//!
+//! Each table is named after a file in `input/` and holds the expected answer
+//! for each part. Multi-line answers, like day 10's message, use a `'''`
+//! literal:
+//!
+//! ```toml
+//! ["day13.txt"]
+//! part1 = "117,62"
+//! part2 = "69,67"
+//! ```
+//!
+//! Only this small part of TOML is understood: tables, comments, integers,
+//! basic strings and multi-line literal strings.
+
+use crate::error::{Error, Result};
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The expected answers for one input file.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Expected answers for one day, keyed by input file name.
+pub type Answers = BTreeMap<String, Expected>;
+
+pub fn path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{:02}.toml", day))
+}
+
+/// Loads a day's answers file. A day without one has no answers yet.
+pub fn load(dir: &Path, day: u8) -> Result<Answers> {
+    match fs::read_to_string(path(dir, day)) {
+        Ok(toml) => parse(day, &toml),
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+const TABLE: &str = "a table like `[\"day01.txt\"]`";
+const KEY: &str = "`part1 = ...` or `part2 = ...`";
+const VALUE: &str = "an integer, a \"string\" or a '''literal'''";
+
+pub fn parse(day: u8, toml: &str) -> Result<Answers> {
+    let mut answers = Answers::new();
+    let mut table: Option<String> = None;
+    let mut lines = toml.lines().enumerate();
+
+    while let Some((index, line)) = lines.next() {
+        let number = index + 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if trimmed.starts_with('[') {
+            let name = trimmed.strip_prefix('[')
+                .and_then(|t| t.strip_suffix(']'))
+                .map(|t| unquote(t.trim()))
+                .filter(|t| !t.is_empty())
+                .ok_or_else(|| Error::parse(day, 1, trimmed, TABLE).at_line(number))?;
+            answers.entry(name.to_string()).or_default();
+            table = Some(name.to_string());
+            continue;
+        }
+
+        let (key, value) = match trimmed.find('=') {
+            Some(eq) => (trimmed[..eq].trim(), trimmed[eq + 1..].trim()),
+            None => return Err(Error::parse(day, 1, trimmed, KEY).at_line(number)),
+        };
+
+        let value_column = line.len() - line.trim_start().len() + trimmed.len() - value.len() + 1;
+        let value = if let Some(rest) = value.strip_prefix("'''") {
+            multi_line(rest, &mut lines)
+                .ok_or_else(|| Error::parse(day, value_column, value, "a closing `'''`").at_line(number))?
+        } else if value.starts_with('"') {
+            basic_string(value)
+                .ok_or_else(|| Error::parse(day, value_column, value, VALUE).at_line(number))?
+        } else if !value.is_empty() && value.trim_start_matches('-').chars().all(|c| c.is_ascii_digit()) {
+            value.to_string()
+        } else {
+            return Err(Error::parse(day, value_column, value, VALUE).at_line(number));
+        };
+
+        let expected = match &table {
+            Some(table) => answers.get_mut(table).expect("table was just added"),
+            None => return Err(Error::parse(day, 1, trimmed, TABLE).at_line(number)),
+        };
+
+        match key {
+            "part1" => expected.part1 = Some(value),
+            "part2" => expected.part2 = Some(value),
+            _ => return Err(Error::parse(day, 1, key, KEY).at_line(number)),
+        }
+    }
+
+    Ok(answers)
+}
+
+fn unquote(s: &str) -> &str {
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(s)
+}
+
+fn basic_string(s: &str) -> Option<String> {
+    let mut chars = s[1..].chars();
+    let mut string = String::new();
+
+    loop {
+        match chars.next()? {
+            '"' => break,
+            '\\' => string.push(match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                c @ '"' | c @ '\\' => c,
+                _ => return None,
+            }),
+            c => string.push(c),
+        }
+    }
+
+    match chars.as_str().trim() {
+        rest if rest.is_empty() || rest.starts_with('#') => Some(string),
+        _ => None,
+    }
+}
+
+/// Reads a `'''` literal. Like TOML, a newline straight after the opening
+/// quotes isn't part of the string.
+fn multi_line<'a, I>(first: &str, lines: &mut I) -> Option<String>
+where I: Iterator<Item = (usize, &'a str)> {
+    if let Some(end) = first.find("'''") {
+        return Some(first[..end].to_string());
+    }
+
+    let mut string = String::new();
+    if !first.is_empty() {
+        string.push_str(first);
+        string.push('\n');
+    }
+
+    for (_, line) in lines {
+        match line.find("'''") {
+            Some(end) => {
+                string.push_str(&line[..end]);
+                return Some(string);
+            },
+            None => {
+                string.push_str(line);
+                string.push('\n');
+            },
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() -> Result<()> {
+        let toml = "\
+# Day 10
+[\"day10.txt\"]
+part1 = '''
+#  #
+####
+'''
+part2 = 10105
+
+[day10_example.txt]
+part1 = \"HI \\\"there\\\"\" # comment
+";
+        let answers = parse(10, toml)?;
+        assert_eq!(answers["day10.txt"].part(1), Some("#  #\n####\n"));
+        assert_eq!(answers["day10.txt"].part(2), Some("10105"));
+        assert_eq!(answers["day10_example.txt"].part(1), Some("HI \"there\""));
+        assert_eq!(answers["day10_example.txt"].part(2), None);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_answers() {
+        let err = parse(1, "part1 = 4").unwrap_err().to_string();
+        assert!(err.contains("line 1"), "{}", err);
+
+        let err = parse(1, "[day01.txt]\npart1 = four").unwrap_err().to_string();
+        assert!(err.contains("line 2, column 9"), "{}", err);
+
+        let err = parse(1, "[day01.txt]\npart3 = 4").unwrap_err().to_string();
+        assert!(err.contains("line 2"), "{}", err);
+
+        assert!(parse(1, "[day01.txt]\npart1 = '''\nnever closed").is_err());
+    }
+}
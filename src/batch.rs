@@ -0,0 +1,140 @@
+//! Runs every day at once, each on its own input, for `aoc run --all`.
+//!
+//! Days run in parallel on rayon's pool. Each part gets its own thread as
+//! well, so a part that panics or runs past the timeout only costs that part
+//! its answer. Solvers that keep to their [`budget`] stop at the timeout;
+//! any others are left running on their thread.
+
+use crate::budget;
+use crate::error::Error;
+use crate::input;
+use crate::registry::{self, Day};
+use crate::solution::Answer;
+
+use rayon::prelude::*;
+
+use std::any::Any;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved {
+        answer: Answer,
+        parse: Duration,
+        elapsed: Duration,
+    },
+    /// The input didn't parse
+    Error(String),
+    Panicked(String),
+    /// Ran out of time, having taken `steps` if the solver counts them
+    Timeout {
+        after: Duration,
+        steps: Option<u64>,
+    },
+    /// There's no input file for the day
+    NoInput,
+}
+
+impl Outcome {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Outcome::Solved { .. } | Outcome::NoInput)
+    }
+}
+
+/// One part of one day.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub title: &'static str,
+    pub part: u8,
+    /// Where the input came from
+    pub input: String,
+    pub outcome: Outcome,
+}
+
+/// Runs every registered day, sorted by day and part.
+pub fn run_all(example: bool, timeout: Duration) -> Vec<Report> {
+    let mut reports = registry::DAYS.par_iter()
+        .flat_map_iter(|day| run_day(day, example, timeout))
+        .collect::<Vec<Report>>();
+
+    reports.sort_by_key(|r| (r.day, r.part));
+    reports
+}
+
+/// Runs both parts of `day` on its input file, or its example.
+pub fn run_day(day: &'static Day, example: bool, timeout: Duration) -> Vec<Report> {
+    let path = input::path(day.day, example);
+    let text = fs::read_to_string(&path).ok();
+
+    (1..=2)
+        .filter(|&part| day.part(part).is_some())
+        .map(|part| {
+            let outcome = match &text {
+                Some(text) => run_part(day, part, text, timeout),
+                None => Outcome::NoInput,
+            };
+
+            Report {
+                day: day.day,
+                title: day.title,
+                part,
+                input: path.display().to_string(),
+                outcome,
+            }
+        })
+        .collect()
+}
+
+/// Solves one part on a thread of its own, within `timeout`. A solver that
+/// doesn't stop for its budget is left to finish, or to die with the process.
+fn run_part(day: &'static Day, part: u8, text: &str, timeout: Duration) -> Outcome {
+    let (tx, rx) = mpsc::channel();
+    let text = text.to_string();
+
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            budget::limit(timeout, || (day.timed)(&text, &[part]))
+        }));
+        let _ = tx.send(result);
+    });
+
+    match rx.recv_timeout(timeout + budget::GRACE) {
+        Ok(Ok(Ok(mut run))) => match run.parts.pop() {
+            Some((_, answer, elapsed)) => Outcome::Solved { answer, parse: run.parse, elapsed },
+            None => Outcome::Error(format!("Day {} has no part {}", day.day, part)),
+        },
+        Ok(Ok(Err(Error::Timeout { steps, after }))) => Outcome::Timeout { after, steps: Some(steps) },
+        Ok(Ok(Err(e))) => Outcome::Error(e.to_string()),
+        Ok(Err(payload)) => Outcome::Panicked(panic_message(payload)),
+        Err(mpsc::RecvTimeoutError::Timeout) => Outcome::Timeout { after: timeout, steps: None },
+        Err(mpsc::RecvTimeoutError::Disconnected) => Outcome::Panicked("the solver thread died".to_string()),
+    }
+}
+
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+#[test]
+fn panics_are_contained() {
+    fn boom(_: &str, _: &[u8]) -> crate::error::Result<registry::Run> {
+        panic!("boom")
+    }
+
+    static DAY: Day = Day { timed: boom, ..Day::new::<crate::day01::Day01>() };
+    assert_eq!(run_part(&DAY, 1, "+1", Duration::from_secs(5)), Outcome::Panicked("boom".to_string()));
+
+    let outcome = run_part(registry::get(1).unwrap(), 1, "+1\n+2\n", Duration::from_secs(5));
+    assert!(matches!(outcome, Outcome::Solved { answer: Answer::Number(3), .. }), "{:?}", outcome);
+}
@@ -0,0 +1,89 @@
+//! Time budgets for solvers that could run for a very long time.
+//!
+//! The runner gives a solve a deadline with [`limit`]. Solvers with long
+//! loops call [`step`] once per tick or generation; once the deadline has
+//! passed it returns false, the loop gives up, and [`limit`] reports the
+//! timeout and how many steps were taken instead of the answer.
+
+use crate::error::{Error, Result};
+
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static BUDGET: RefCell<Option<Budget>> = const { RefCell::new(None) };
+}
+
+/// How long past its deadline a solver running on another thread gets to
+/// notice, before the runner stops waiting for it.
+pub const GRACE: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy)]
+struct Budget {
+    deadline: Instant,
+    steps: u64,
+    expired: bool,
+}
+
+/// Puts the previous budget back, even if the solver panics.
+struct Restore(Option<Budget>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        BUDGET.with(|budget| *budget.borrow_mut() = self.0.take());
+    }
+}
+
+/// Runs `solve` with `timeout` to spend. Budgets don't nest: the innermost
+/// one is the one that counts.
+pub fn limit<T, F: FnOnce() -> Result<T>>(timeout: Duration, solve: F) -> Result<T> {
+    let budget = Budget { deadline: Instant::now() + timeout, steps: 0, expired: false };
+    let restore = Restore(BUDGET.with(|b| b.borrow_mut().replace(budget)));
+
+    let result = solve();
+
+    let spent = BUDGET.with(|b| *b.borrow());
+    drop(restore);
+
+    match spent {
+        Some(Budget { expired: true, steps, .. }) => Err(Error::Timeout { steps, after: timeout }),
+        _ => result,
+    }
+}
+
+/// Counts a step, and returns false if the budget has run out. Always true
+/// when there's no budget.
+pub fn step() -> bool {
+    BUDGET.with(|budget| match &mut *budget.borrow_mut() {
+        Some(budget) => {
+            if !budget.expired {
+                budget.steps += 1;
+                budget.expired = Instant::now() >= budget.deadline;
+            }
+            !budget.expired
+        },
+        None => true,
+    })
+}
+
+#[test]
+fn cancels() {
+    let forever = || -> Result<u64> {
+        let mut n = 0;
+        while step() {
+            n += 1;
+        }
+        Ok(n)
+    };
+
+    match limit(Duration::from_millis(20), forever) {
+        Err(Error::Timeout { steps, after }) => {
+            assert!(steps > 0);
+            assert_eq!(after, Duration::from_millis(20));
+        },
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+
+    assert_eq!(limit(Duration::from_secs(5), || Ok(42)).unwrap(), 42);
+    assert!(step());
+}
@@ -0,0 +1,442 @@
+//! Day 1: Chronal Calibration
+
+use crate::error::Error;
+use crate::parse;
+use crate::reference::Reference;
+use crate::solution::{Answer, Result, Solution};
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::io::BufRead;
+
+pub fn parse(input: &str) -> Result<Vec<isize>> {
+    parse::lines(input, change)
+}
+
+pub fn change(line: &str) -> Result<isize> {
+    line.trim().parse()
+        .map_err(|_| Error::parse(Day01::DAY, 1, line, "a frequency change like `+7` or `-3`"))
+}
+
+/// Where a [`Calibrator`] ends up, the same as `bin/day01` streams it.
+pub fn part1(vec_isize: &[isize]) -> i128 {
+    let mut calibrator = Calibrator::new();
+    calibrator.extend(vec_isize.iter().copied());
+    calibrator.frequency()
+}
+
+/// The first frequency reached twice, going round the changes as many
+/// times as it takes, or None if no frequency ever comes round again.
+/// Worked out in [`part2_closed_form`], so it takes no longer when that
+/// would be a billion times round.
+pub fn part2(changes: &[isize]) -> Option<i128> {
+    part2_closed_form(changes)
+}
+
+/// Works out [`part2`] without going round at all. Every pass adds the
+/// drift (the answer to [`part1`]) to each of the first pass's frequencies,
+/// so a frequency can only come round to one the first pass reached that
+/// is a whole number of drifts ahead of it. The first to get there is the
+/// answer.
+pub fn part2_closed_form(changes: &[isize]) -> Option<i128> {
+    first_repeat(&prefixes(changes)).map(|(_, frequency)| frequency)
+}
+
+/// How many changes it takes to reach a frequency twice going round, and
+/// which frequency that is, from the frequencies [`prefixes`] gives.
+fn first_repeat(prefixes: &[i128]) -> Option<(i128, i128)> {
+    let (&drift, prefixes) = prefixes.split_last()?;
+    if prefixes.is_empty() {
+        return None;
+    }
+    let n = prefixes.len() as i128;
+
+    // A repeat within the first pass beats any later one. Without any drift
+    // the second pass starts back at 0, if nothing repeated before then
+    let mut seen = HashSet::new();
+    if let Some((index, &frequency)) = prefixes.iter().enumerate().find(|&(_, &f)| !seen.insert(f)) {
+        return Some((index as i128, frequency));
+    }
+    if drift == 0 {
+        return Some((n, 0));
+    }
+
+    // Going the other way round is the same with every frequency negated
+    let sign = drift.signum();
+    let step = drift.abs();
+
+    // Frequencies that can reach each other, in the order they're reached
+    let mut classes = prefixes.iter()
+        .map(|&f| f * sign)
+        .enumerate()
+        .map(|(index, f)| (f.rem_euclid(step), f, index))
+        .collect::<Vec<(i128, i128, usize)>>();
+    classes.sort_unstable();
+
+    // Each frequency next repeats on reaching the one just ahead of it. The
+    // one that gets there in the fewest changes is first
+    classes.windows(2)
+        .filter(|pair| pair[0].0 == pair[1].0)
+        .map(|pair| {
+            let ((_, from, index), (_, to, _)) = (pair[0], pair[1]);
+            let passes = (to - from) / step;
+            (passes.saturating_mul(n).saturating_add(index as i128), to * sign)
+        })
+        .min()
+}
+
+/// The frequency before each change, and after the last, starting from 0.
+/// Kept wide so that a long run of big changes can't overflow.
+fn prefixes(changes: &[isize]) -> Vec<i128> {
+    let mut prefixes = Vec::with_capacity(changes.len() + 1);
+    let mut frequency: i128 = 0;
+    prefixes.push(frequency);
+    for &change in changes {
+        frequency += change as i128;
+        prefixes.push(frequency);
+    }
+
+    prefixes
+}
+
+/// Follows the frequency one change at a time, so the changes can come
+/// from a stream too big to read in at once, or one still being written.
+///
+/// Frequencies are kept as `i128`, which no run of `isize` changes is going
+/// to overflow.
+#[derive(Debug)]
+pub struct Calibrator {
+    changes: Vec<isize>,
+    frequency: i128,
+    seen: HashSet<i128>,
+    first_repeat: Option<i128>,
+    passes: usize,
+    lines: usize,
+    rejected: Vec<Error>,
+}
+
+impl Default for Calibrator {
+    fn default() -> Calibrator {
+        Calibrator::new()
+    }
+}
+
+impl Calibrator {
+    pub fn new() -> Calibrator {
+        Calibrator {
+            changes: Vec::new(),
+            frequency: 0,
+            seen: [0].iter().copied().collect(),
+            first_repeat: None,
+            passes: 0,
+            lines: 0,
+            rejected: Vec::new(),
+        }
+    }
+
+    /// Applies the next change, returning the frequency if it's the first
+    /// to be reached twice.
+    pub fn apply(&mut self, change: isize) -> Option<i128> {
+        // Going round again has to start over with this change in
+        if self.passes > 1 {
+            self.first_repeat = None;
+        }
+        self.passes = 1;
+        self.changes.push(change);
+
+        self.frequency += change as i128;
+        if !self.seen.insert(self.frequency) && self.first_repeat.is_none() {
+            self.first_repeat = Some(self.frequency);
+            return self.first_repeat;
+        }
+
+        None
+    }
+
+    /// Applies the change on `line`, or keeps the error if there isn't one.
+    /// Blank lines are skipped, but still counted.
+    pub fn line(&mut self, line: &str) {
+        self.lines += 1;
+        if line.trim().is_empty() {
+            return;
+        }
+
+        match change(line) {
+            Ok(change) => {
+                self.apply(change);
+            },
+            Err(e) => self.rejected.push(e.at_line(self.lines)),
+        }
+    }
+
+    /// Applies every line `reader` gives, until it runs out. Only failing
+    /// to read stops it; lines that aren't changes end up in
+    /// [`rejected`](Calibrator::rejected).
+    pub fn read<R: BufRead>(&mut self, mut reader: R) -> Result<()> {
+        let mut buffer = Vec::new();
+        loop {
+            buffer.clear();
+            if reader.read_until(b'\n', &mut buffer)? == 0 {
+                return Ok(());
+            }
+            let line = String::from_utf8_lossy(&buffer);
+            self.line(line.trim_end_matches(&['\n', '\r'][..]));
+        }
+    }
+
+    /// The first frequency reached going round the changes again and
+    /// again, or None if none ever is. Worked out from the first pass the
+    /// way [`part2_closed_form`] does, however many passes it would take.
+    /// Meant for once all the changes are in:
+    /// [`frequency`](Calibrator::frequency) stays where the changes so far
+    /// leave it, and applying another starts over from the first pass.
+    pub fn go_round(&mut self) -> Option<i128> {
+        if self.first_repeat.is_none() {
+            if let Some((steps, frequency)) = first_repeat(&prefixes(&self.changes)) {
+                // Counting the pass it's reached partway through
+                let n = self.changes.len() as i128;
+                self.passes = usize::try_from(steps / n + (steps % n).signum()).unwrap_or(usize::MAX);
+                self.first_repeat = Some(frequency);
+            }
+        }
+
+        self.first_repeat
+    }
+
+    /// The frequency after every change so far.
+    pub fn frequency(&self) -> i128 {
+        self.frequency
+    }
+
+    /// The first frequency reached twice, if one has been yet.
+    pub fn first_repeat(&self) -> Option<i128> {
+        self.first_repeat
+    }
+
+    /// How many times round the changes it takes to reach the first repeat,
+    /// counting the pass it's reached partway through. Until
+    /// [`go_round`](Calibrator::go_round) finds one past the first pass,
+    /// that's the one pass so far.
+    pub fn passes(&self) -> usize {
+        self.passes
+    }
+
+    /// How many changes have been read.
+    pub fn changes(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Every line that wasn't a change, with its line number.
+    pub fn rejected(&self) -> &[Error] {
+        &self.rejected
+    }
+}
+
+impl Extend<isize> for Calibrator {
+    fn extend<I: IntoIterator<Item = isize>>(&mut self, changes: I) {
+        for change in changes {
+            self.apply(change);
+        }
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Chronal Calibration";
+
+    type Input = Vec<isize>;
+
+    fn parse(input: &str) -> Result<Vec<isize>> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<Error> {
+        parse::check_lines(input, change)
+    }
+
+    fn part1(input: &Vec<isize>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Vec<isize>) -> Answer {
+        match part2(input) {
+            Some(frequency) => frequency.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+impl Reference for Day01 {
+    fn part1(input: &Vec<isize>) -> Answer {
+        reference::part1(input).into()
+    }
+
+    fn part2(input: &Vec<isize>) -> Answer {
+        reference::part2(input).into()
+    }
+}
+
+/// Deliberately simple versions of both parts, to check the ones above
+/// against.
+pub mod reference {
+    use crate::budget;
+
+    pub fn part1(changes: &[isize]) -> i128 {
+        let mut frequency = 0;
+        for &change in changes {
+            frequency += change as i128;
+        }
+        frequency
+    }
+
+    pub fn part2(changes: &[isize]) -> i128 {
+        let mut seen = vec![0];
+        let mut frequency = 0;
+
+        while !changes.is_empty() && budget::step() {
+            for &change in changes {
+                frequency += change as i128;
+                if seen.contains(&frequency) {
+                    return frequency;
+                }
+                seen.push(frequency);
+            }
+        }
+
+        frequency
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::{self, Rng};
+
+    /// Goes round with a hash set, to check the closed form against, and
+    /// counts the passes it takes.
+    fn going_round(changes: &[isize]) -> (Option<i128>, usize) {
+        let frequencies = prefixes(changes);
+        let drift = frequencies[changes.len()];
+        let spread = frequencies.iter().max().unwrap() - frequencies.iter().min().unwrap();
+        // Each pass moves every frequency on by the drift, so once it has
+        // carried them further than the first pass spread out, none can
+        // come round again
+        let most = if drift == 0 { 2 } else { spread / drift.abs() + 2 };
+
+        let mut seen = HashSet::new();
+        seen.insert(0);
+        let mut frequency = 0;
+        for passes in 1..=most as usize {
+            for &change in changes {
+                frequency += change as i128;
+                if !seen.insert(frequency) {
+                    return (Some(frequency), passes);
+                }
+            }
+        }
+
+        (None, 1)
+    }
+
+    /// Checks both the closed form and a calibrator going round.
+    fn agrees(changes: &[isize]) {
+        let (expected, passes) = going_round(changes);
+        assert_eq!(part2_closed_form(changes), expected, "{:?}", changes);
+
+        let mut calibrator = Calibrator::new();
+        calibrator.extend(changes.iter().copied());
+        assert_eq!(calibrator.go_round(), expected, "{:?}", changes);
+        if expected.is_some() {
+            assert_eq!(calibrator.passes(), passes, "{:?}", changes);
+        }
+    }
+
+    #[test]
+    fn closed_form_agrees() {
+        let examples: &[&[isize]] = &[
+            &[1, -2, 3, 1], &[1, -1], &[3, 3, 4, -2, -4], &[-6, 3, 8, 5, -6], &[7, 7, -2, -7, -4],
+            &[1, 1], &[-5], &[0], &[2, -1, -3, 4], &[-2, 1, 3, -4, -1],
+        ];
+        for changes in examples {
+            agrees(changes);
+        }
+
+        let rng = &mut Rng::new(1);
+        for _ in 0..500 {
+            let changes = (0..rng.range(1, 8)).map(|_| rng.range(-9, 10) as isize).collect::<Vec<isize>>();
+            agrees(&changes);
+        }
+
+        let input = parse(&gen::input(1, 0).unwrap()).unwrap();
+        agrees(&input);
+    }
+
+    #[test]
+    fn calibrates_a_stream() {
+        let mut calibrator = Calibrator::new();
+        calibrator.read("+3\n+3\nfour\n\n+4\n-2\n-4\n".as_bytes()).unwrap();
+
+        assert_eq!(calibrator.frequency(), 4);
+        assert_eq!(calibrator.changes(), 5);
+        assert_eq!(calibrator.first_repeat(), None);
+        assert_eq!(calibrator.passes(), 1);
+        match calibrator.rejected() {
+            [Error::Parse { line: 3, text, .. }] => assert_eq!(text, "four"),
+            other => panic!("expected line 3 to be rejected, got {:?}", other),
+        }
+
+        assert_eq!(calibrator.go_round(), Some(10));
+        assert_eq!(calibrator.passes(), 2);
+        assert_eq!(calibrator.frequency(), 4);
+        assert_eq!(calibrator.go_round(), Some(10));
+
+        // Another change starts over, from where the stream left off
+        assert_eq!(calibrator.apply(1), None);
+        assert_eq!(calibrator.frequency(), 5);
+        assert_eq!(calibrator.passes(), 1);
+        assert_eq!(calibrator.first_repeat(), None);
+        assert_eq!(calibrator.go_round(), part2_closed_form(&[3, 3, 4, -2, -4, 1]));
+        assert_eq!(calibrator.frequency(), 5);
+
+        // It's noticed as soon as it happens
+        let mut calibrator = Calibrator::new();
+        assert_eq!(calibrator.apply(1), None);
+        assert_eq!(calibrator.apply(-1), Some(0));
+        assert_eq!(calibrator.apply(1), None);
+        assert_eq!(calibrator.first_repeat(), Some(0));
+    }
+
+    #[test]
+    fn never_repeats() {
+        assert_eq!(part2(&[1, 1]), None);
+        assert_eq!(part2_closed_form(&[1, 1]), None);
+        assert_eq!(part2(&[]), None);
+        assert_eq!(part2_closed_form(&[isize::MAX, isize::MAX]), None);
+    }
+
+    #[test]
+    fn wide_first_pass() {
+        // A billion passes before 0 drifts up to where the first pass went
+        let changes = parse("+1000000000\n-999999999\n").unwrap();
+        assert_eq!(part2(&changes), Some(1_000_000_000));
+        assert_eq!(<Day01 as Solution>::part2(&changes), Answer::Number(1_000_000_000));
+
+        let mut calibrator = Calibrator::new();
+        calibrator.read("+1000000000\n-999999999\n".as_bytes()).unwrap();
+        assert_eq!(calibrator.go_round(), Some(1_000_000_000));
+        assert_eq!(calibrator.passes(), 1_000_000_000);
+        assert_eq!(calibrator.frequency(), 1);
+    }
+
+    #[test]
+    fn wide_frequencies() {
+        assert_eq!(part1(&[isize::MIN, -1]), isize::MIN as i128 - 1);
+        assert_eq!(<Day01 as Solution>::part1(&parse("-9223372036854775808\n-1\n").unwrap()).to_string(), "-9223372036854775809");
+
+        let past_max = isize::MAX as i128 + 1;
+        assert_eq!(part2(&[isize::MAX, 1, 0]), Some(past_max));
+        assert_eq!(part2_closed_form(&[isize::MAX, 1, 0]), Some(past_max));
+    }
+}
@@ -0,0 +1,307 @@
+//! Day 2: Inventory Management System
+
+use crate::error::Error;
+use crate::parse;
+use crate::reference::Reference;
+use crate::solution::{Answer, Result, Solution};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::iter::FromIterator;
+use std::ops::Range;
+
+pub fn parse(input: &str) -> Result<Vec<String>> {
+    parse::lines(input, id)
+}
+
+pub fn id(line: &str) -> Result<String> {
+    let id = line.trim();
+    match id.chars().position(char::is_whitespace) {
+        Some(index) => Err(Error::parse(Day02::DAY, index + 1, id, "a box ID without spaces")),
+        None => Ok(id.to_string()),
+    }
+}
+
+pub fn part1(lines: &[String]) -> usize {
+    checksum(lines, &[2, 3])
+}
+
+/// For each of `multiplicities`, how many IDs have some character exactly
+/// that many times, all multiplied together.
+pub fn checksum(ids: &[String], multiplicities: &[usize]) -> usize {
+    let counts = profiles(ids).iter()
+        .map(Profile::multiplicities)
+        .fold(vec![0; multiplicities.len()], |mut counts, has| {
+            for (count, n) in counts.iter_mut().zip(multiplicities) {
+                if has.contains(n) {
+                    *count += 1;
+                }
+            }
+            counts
+        });
+
+    counts.into_iter().product()
+}
+
+pub fn profiles(ids: &[String]) -> Vec<Profile> {
+    ids.iter().map(|id| Profile::of(id)).collect()
+}
+
+/// How many times each character appears in an ID. Characters are Unicode
+/// scalar values, so `é` is one character whatever its bytes, though a
+/// combining accent counts as one of its own.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile(pub BTreeMap<char, usize>);
+
+impl Profile {
+    pub fn of(id: &str) -> Profile {
+        let mut counts = BTreeMap::new();
+        for c in id.chars() {
+            *counts.entry(c).or_insert(0) += 1;
+        }
+
+        Profile(counts)
+    }
+
+    pub fn count(&self, c: char) -> usize {
+        self.0.get(&c).copied().unwrap_or(0)
+    }
+
+    /// Every number of times some character appears.
+    pub fn multiplicities(&self) -> BTreeSet<usize> {
+        self.0.values().copied().collect()
+    }
+
+    /// Whether some character appears exactly `n` times.
+    pub fn has(&self, n: usize) -> bool {
+        self.0.values().any(|&count| count == n)
+    }
+}
+
+#[test]
+fn checksums() {
+    let ids = ["abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab"]
+        .iter().map(|id| id.to_string()).collect::<Vec<String>>();
+    assert_eq!(checksum(&ids, &[2, 3]), 12);
+    assert_eq!(checksum(&ids, &[1]), 6);
+    assert_eq!(checksum(&ids, &[1, 2, 3]), 72);
+    assert_eq!(checksum(&ids, &[4]), 0);
+
+    let profile = Profile::of("ñandú ñu");
+    assert_eq!(profile.count('ñ'), 2);
+    assert_eq!(profile.count('ú'), 1);
+    assert_eq!(profile.count('u'), 1);
+    assert!(profile.has(2) && !profile.has(3));
+    assert_eq!(checksum(&["ééè".to_string(), "èè".to_string()], &[2]), 2);
+}
+
+pub fn diff_count(s1: &str, s2: &str) -> usize {
+    let btree1 = BTreeMap::from_iter(s1.char_indices());
+    let btree2 = BTreeMap::from_iter(s2.char_indices());
+
+    let longest;
+    let other;
+    if btree1.len() >= btree2.len() {
+        longest = &btree1;
+        other = &btree2;
+    } else {
+        longest = &btree2;
+        other = &btree1;
+    }
+    
+    longest.iter()
+        .filter(|(i, c)|
+            *c != other.get(i).unwrap_or(&' ')
+        ).count()
+}
+
+#[test]
+fn diff() {
+    let s1 = "abcde";
+    let s2 = "axcye";
+    let s3 = "abcdef";
+    assert_eq!(diff_count(s1, s2), 2);
+    assert_eq!(diff_count(s1, s3), 1);
+}
+
+/// The letters the two IDs differing by one letter share, or nothing if
+/// there aren't two.
+pub fn part2(lines: &[String]) -> String {
+    near_pairs(lines, 1).into_iter()
+        .find(|pair| pair.positions.len() == 1)
+        .map_or_else(String::new, |pair| pair.common)
+}
+
+/// Two IDs of the same length that differ in at most a few places.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NearPair {
+    /// Where the two IDs are in the list, the earlier first
+    pub ids: (usize, usize),
+    /// The character positions they differ at
+    pub positions: Vec<usize>,
+    /// What [`common_chars`] makes of them
+    pub common: String,
+}
+
+/// Every pair of IDs that differ in at most `distance` places, in the
+/// order they're listed. Only IDs of the same length can be near each other.
+///
+/// Split into `distance + 1` blocks, two IDs that close have to match
+/// exactly on one block at least. So only IDs sharing a block are compared,
+/// each pair at the first block they share, which for IDs that aren't all
+/// alike is next to none of them.
+pub fn near_pairs(ids: &[String], distance: usize) -> Vec<NearPair> {
+    let chars = ids.iter().map(|id| id.chars().collect()).collect::<Vec<Vec<char>>>();
+    let mut buckets: HashMap<(usize, usize, &[char]), Vec<usize>> = HashMap::new();
+
+    for (index, id) in chars.iter().enumerate() {
+        for (block, range) in blocks(id.len(), distance).enumerate() {
+            buckets.entry((id.len(), block, &id[range])).or_default().push(index);
+        }
+    }
+
+    let mut pairs = Vec::new();
+    for ((len, block, _), bucket) in &buckets {
+        let earlier_blocks = blocks(*len, distance).take(*block).collect::<Vec<Range<usize>>>();
+
+        for (i, &a) in bucket.iter().enumerate() {
+            for &b in &bucket[i + 1..] {
+                let (x, y) = (&chars[a], &chars[b]);
+                if earlier_blocks.iter().any(|range| x[range.clone()] == y[range.clone()]) {
+                    continue;
+                }
+
+                let positions = (0..x.len()).filter(|&p| x[p] != y[p]).collect::<Vec<usize>>();
+                if positions.len() <= distance {
+                    let common = common_chars(&ids[a], &ids[b]);
+                    pairs.push(NearPair { ids: (a.min(b), a.max(b)), positions, common });
+                }
+            }
+        }
+    }
+
+    pairs.sort_by_key(|pair| pair.ids);
+    pairs
+}
+
+/// Splits `len` positions into `distance + 1` blocks, as evenly as they go.
+/// With more blocks than positions, some are empty, which every ID matches.
+fn blocks(len: usize, distance: usize) -> impl Iterator<Item = Range<usize>> {
+    let count = distance.saturating_add(1).min(len + 1);
+    (0..count).map(move |block| block * len / count..(block + 1) * len / count)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Inventory Management System";
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<Error> {
+        parse::check_lines(input, id)
+    }
+
+    fn part1(input: &Vec<String>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Vec<String>) -> Answer {
+        part2(input).into()
+    }
+}
+
+impl Reference for Day02 {
+    fn part1(input: &Vec<String>) -> Answer {
+        reference::part1(input).into()
+    }
+
+    fn part2(input: &Vec<String>) -> Answer {
+        reference::part2(input).into()
+    }
+}
+
+/// Deliberately simple versions of both parts, to check the ones above
+/// against.
+pub mod reference {
+    pub fn part1(ids: &[String]) -> usize {
+        let has = |id: &String, n: usize| id.chars().any(|c| id.chars().filter(|&d| d == c).count() == n);
+
+        ids.iter().filter(|id| has(id, 2)).count() * ids.iter().filter(|id| has(id, 3)).count()
+    }
+
+    /// The letters the two IDs differing by one letter share, or nothing if
+    /// there aren't two.
+    pub fn part2(ids: &[String]) -> String {
+        for (i, a) in ids.iter().enumerate() {
+            for b in &ids[i + 1..] {
+                let same = a.chars().zip(b.chars())
+                    .filter(|(x, y)| x == y)
+                    .map(|(x, _)| x)
+                    .collect::<String>();
+                if a.len() == b.len() && same.len() + 1 == a.len() {
+                    return same;
+                }
+            }
+        }
+
+        String::new()
+    }
+}
+
+/// The characters `s1` and `s2` have in the same places.
+pub fn common_chars(s1: &str, s2: &str) -> String {
+    s1.chars().zip(s2.chars())
+        .filter(|(a, b)| a == b)
+        .map(|(a, _)| a)
+        .collect()
+}
+
+#[test]
+fn common() {
+    let s1 = "abcde";
+    let s2 = "axcye";
+    let s3 = "abcdef";
+    assert_eq!(common_chars(s1, s2), "ace");
+    assert_eq!(common_chars(s1, s3), "abcde");
+}
+
+#[test]
+fn near() {
+    let ids = ["abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz", "abcdf", "fguij"]
+        .iter().map(|id| id.to_string()).collect::<Vec<String>>();
+
+    let pairs = near_pairs(&ids, 1);
+    let found = pairs.iter().map(|p| (p.ids, p.positions.clone(), p.common.as_str())).collect::<Vec<_>>();
+    assert_eq!(found, vec![
+        ((0, 7), vec![4], "abcd"),
+        ((1, 4), vec![2], "fgij"),
+        ((1, 8), vec![2], "fgij"),
+        ((4, 8), vec![], "fguij"),
+    ]);
+
+    let pairs = near_pairs(&ids, 2);
+    assert!(pairs.iter().any(|p| p.ids == (0, 5) && p.positions == [1, 3] && p.common == "ace"));
+    assert_eq!(pairs.len(), 5);
+    assert_eq!(near_pairs(&ids, 5).len(), ids.len() * (ids.len() - 1) / 2);
+
+    // Against comparing every pair, on IDs short and alike enough to have
+    // plenty of near pairs
+    let rng = &mut crate::gen::Rng::new(2);
+    for distance in 0..4 {
+        let ids = (0..40)
+            .map(|_| (0..rng.range(3, 6)).map(|_| if rng.one_in(2) { 'a' } else { 'b' }).collect())
+            .collect::<Vec<String>>();
+        let every = (0..ids.len())
+            .flat_map(|a| (a + 1..ids.len()).map(move |b| (a, b)))
+            .filter(|&(a, b)| ids[a].len() == ids[b].len() && diff_count(&ids[a], &ids[b]) <= distance)
+            .collect::<Vec<(usize, usize)>>();
+        assert_eq!(near_pairs(&ids, distance).iter().map(|p| p.ids).collect::<Vec<_>>(), every);
+    }
+
+    let unicode = vec!["añb".to_string(), "aña".to_string(), "ab".to_string()];
+    assert_eq!(near_pairs(&unicode, 1), vec![NearPair { ids: (0, 1), positions: vec![2], common: "añ".to_string() }]);
+}
@@ -0,0 +1,732 @@
+//! Day 3: No Matter How You Slice It
+
+use crate::error::Error;
+use crate::grid::{Bounds, Point};
+use crate::parse;
+use crate::reference::Reference;
+use crate::solution::{Answer, Result, Solution};
+
+use std::fmt;
+use std::str::FromStr;
+
+pub fn parse(input: &str) -> Result<Vec<Claim>> {
+    parse::lines(input, Claim::from_str)
+}
+
+/// Counts the square inches covered by two or more claims.
+pub fn part1(claims: &[Claim]) -> u128 {
+    Fabric::new(claims).overlap_area()
+}
+
+/// The first claim that overlaps no other, or 0.
+pub fn part2(claims: &[Claim]) -> usize {
+    Fabric::new(claims).lonely().first().map_or(0, |claim| claim.id)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "No Matter How You Slice It";
+
+    type Input = Vec<Claim>;
+
+    fn parse(input: &str) -> Result<Vec<Claim>> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<Error> {
+        parse::check_lines(input, Claim::from_str)
+    }
+
+    fn part1(input: &Vec<Claim>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Vec<Claim>) -> Answer {
+        part2(input).into()
+    }
+}
+
+impl Reference for Day03 {
+    fn part1(input: &Vec<Claim>) -> Answer {
+        if reference::too_big(input) {
+            return Answer::Unsolved;
+        }
+        reference::part1(input).into()
+    }
+
+    fn part2(input: &Vec<Claim>) -> Answer {
+        if reference::too_big(input) {
+            return Answer::Unsolved;
+        }
+        reference::part2(input).into()
+    }
+}
+
+/// Deliberately simple versions of both parts, to check the ones above
+/// against.
+pub mod reference {
+    use super::Claim;
+    use crate::budget;
+    use crate::grid::MAX_AREA;
+    use std::collections::HashMap;
+
+    /// Whether the claims cover more inches between them than it's worth
+    /// counting one at a time.
+    pub fn too_big(claims: &[Claim]) -> bool {
+        claims.iter().map(|claim| claim.rect.area()).fold(0, i64::saturating_add) > MAX_AREA
+    }
+
+    /// How many claims cover each inch, as far as the budget allows.
+    pub fn claimed(claims: &[Claim]) -> HashMap<(i64, i64), usize> {
+        let mut inches = HashMap::new();
+        for claim in claims {
+            for x in claim.rect.min.x..=claim.rect.max.x {
+                if !budget::step() {
+                    return inches;
+                }
+                for y in claim.rect.min.y..=claim.rect.max.y {
+                    *inches.entry((x, y)).or_insert(0) += 1;
+                }
+            }
+        }
+        inches
+    }
+
+    pub fn part1(claims: &[Claim]) -> usize {
+        claimed(claims).values().filter(|&&n| n > 1).count()
+    }
+
+    /// The first claim nobody else has claimed any of, or 0.
+    pub fn part2(claims: &[Claim]) -> usize {
+        let inches = claimed(claims);
+
+        claims.iter()
+            .find(|claim| claim.rect.points().all(|p| inches.get(&(p.x, p.y)) == Some(&1)))
+            .map_or(0, |claim| claim.id)
+    }
+}
+
+/// The claims laid out on the fabric, however big it is.
+///
+/// Questions about it are answered by sweeping down the fabric a row of
+/// claim edges at a time, so they take time in the number of claims, not
+/// the size of the fabric.
+#[derive(Debug, Clone)]
+pub struct Fabric<'a> {
+    claims: &'a [Claim],
+    /// Where each claim starts and stops covering rows, in order down the
+    /// fabric, stops before starts in the same row
+    events: Vec<(i64, Edge, usize)>,
+    by_left: ByLeft,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Edge {
+    Bottom,
+    Top,
+}
+
+impl<'a> Fabric<'a> {
+    pub fn new(claims: &'a [Claim]) -> Fabric<'a> {
+        let mut events = claims.iter()
+            .enumerate()
+            .flat_map(|(i, claim)| vec![(claim.rect.min.y, Edge::Top, i), (claim.rect.max.y + 1, Edge::Bottom, i)])
+            .collect::<Vec<(i64, Edge, usize)>>();
+        events.sort_unstable();
+
+        Fabric { claims, events, by_left: ByLeft::new(claims) }
+    }
+
+    /// The square inches covered by at least one claim.
+    pub fn claimed_area(&self) -> u128 {
+        self.area(|coverage| coverage.once[1])
+    }
+
+    /// The square inches covered by two or more claims.
+    pub fn overlap_area(&self) -> u128 {
+        self.area(|coverage| coverage.twice[1])
+    }
+
+    /// Adds up the width `covered` gives for each band of rows between one
+    /// claim edge and the next.
+    fn area<F: Fn(&Coverage) -> i64>(&self, covered: F) -> u128 {
+        let mut coverage = Coverage::new(self.claims);
+        let mut area = 0;
+        let mut row = self.events.first().map_or(0, |&(y, _, _)| y);
+
+        for &(y, edge, i) in &self.events {
+            area += covered(&coverage) as u128 * (y - row) as u128;
+            row = y;
+
+            let rect = self.claims[i].rect;
+            coverage.add(rect.min.x, rect.max.x + 1, if edge == Edge::Top { 1 } else { -1 });
+        }
+
+        area
+    }
+
+    /// Every claim that overlaps no other, in the order they were made.
+    ///
+    /// Going down the fabric, each claim is checked as it starts against
+    /// those still running. Ones it overlaps that haven't overlapped
+    /// anything yet are taken out of the running for being lonely, so each
+    /// claim is only ever found that way once.
+    pub fn lonely(&self) -> Vec<&'a Claim> {
+        let ByLeft { by_left, lefts, place } = &self.by_left;
+
+        // The right edges of the claims running now, by their left edges,
+        // and of those that are still lonely
+        let mut running = Rightmost::new(self.claims.len());
+        let mut lonely = Rightmost::new(self.claims.len());
+        let mut overlapped = vec![false; self.claims.len()];
+
+        for &(_, edge, i) in &self.events {
+            let rect = self.claims[i].rect;
+            if edge == Edge::Bottom {
+                running.set(place[i], None);
+                lonely.set(place[i], None);
+                continue;
+            }
+
+            // Claims starting left of this one's right edge and ending
+            // right of its left edge overlap it
+            let upto = lefts.partition_point(|&left| left <= rect.max.x);
+            while let Some(at) = lonely.find(upto, rect.min.x) {
+                overlapped[by_left[at]] = true;
+                lonely.set(at, None);
+            }
+
+            overlapped[i] = running.find(upto, rect.min.x).is_some();
+            running.set(place[i], Some(rect.max.x));
+            if !overlapped[i] {
+                lonely.set(place[i], Some(rect.max.x));
+            }
+        }
+
+        self.claims.iter().zip(overlapped).filter(|(_, o)| !o).map(|(claim, _)| claim).collect()
+    }
+
+    /// Every other claim that overlaps the claim at `index`, in the order
+    /// they were made. Only claims starting left of its right edge are
+    /// looked at.
+    pub fn overlapping(&self, index: usize) -> Vec<&'a Claim> {
+        let ByLeft { by_left, lefts, .. } = &self.by_left;
+        let claim = &self.claims[index];
+
+        let upto = lefts.partition_point(|&left| left <= claim.rect.max.x);
+        let mut others = by_left[..upto].iter()
+            .copied()
+            .filter(|&i| i != index && self.claims[i].overlaps(claim))
+            .collect::<Vec<usize>>();
+        others.sort_unstable();
+
+        others.into_iter().map(|i| &self.claims[i]).collect()
+    }
+
+    /// Every pair of claims that overlap, each in the order they were made,
+    /// and the pairs in order of their first claim then their second. Only
+    /// claims running down the same rows are compared, so this takes time
+    /// in how many pairs there are, not in every pair there could be.
+    pub fn overlaps(&self) -> Vec<(&'a Claim, &'a Claim)> {
+        self.pairs().into_iter().map(|(a, b)| (&self.claims[a], &self.claims[b])).collect()
+    }
+
+    /// [`overlaps`](Fabric::overlaps), as indices into the claims.
+    fn pairs(&self) -> Vec<(usize, usize)> {
+        let ByLeft { by_left, lefts, place } = &self.by_left;
+        let mut running = Rightmost::new(self.claims.len());
+        let mut pairs = Vec::new();
+
+        for &(_, edge, i) in &self.events {
+            let rect = self.claims[i].rect;
+            if edge == Edge::Bottom {
+                running.set(place[i], None);
+                continue;
+            }
+
+            let upto = lefts.partition_point(|&left| left <= rect.max.x);
+            for at in running.find_all(upto, rect.min.x) {
+                let other = by_left[at];
+                pairs.push((other.min(i), other.max(i)));
+            }
+            running.set(place[i], Some(rect.max.x));
+        }
+
+        pairs.sort_unstable();
+        pairs
+    }
+
+    /// The claims in groups that overlap each other, directly or through
+    /// other claims in the group. A claim that overlaps nothing is a group
+    /// of its own. Groups are in the order of their first claim, and claims
+    /// in the order they were made.
+    pub fn components(&self) -> Vec<Vec<&'a Claim>> {
+        let mut parent = (0..self.claims.len()).collect::<Vec<usize>>();
+        fn root(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+
+        for (a, b) in self.pairs() {
+            let (a, b) = (root(&mut parent, a), root(&mut parent, b));
+            parent[a.max(b)] = a.min(b);
+        }
+
+        let mut groups: Vec<Vec<&Claim>> = Vec::new();
+        let mut group_of: Vec<Option<usize>> = vec![None; self.claims.len()];
+        for (i, claim) in self.claims.iter().enumerate() {
+            let root = root(&mut parent, i);
+            match group_of[root] {
+                Some(group) => groups[group].push(claim),
+                None => {
+                    group_of[root] = Some(groups.len());
+                    groups.push(vec![claim]);
+                },
+            }
+        }
+
+        groups
+    }
+
+    /// The square inch with the most claims on it, and how many there are.
+    /// The first in reading order if there's a tie, or None without claims.
+    pub fn deepest(&self) -> Option<(Point, usize)> {
+        let mut depth = Depth::new(self.claims);
+        let mut deepest: Option<(Point, usize)> = None;
+
+        for (at, &(y, edge, i)) in self.events.iter().enumerate() {
+            let rect = self.claims[i].rect;
+            depth.add(rect.min.x, rect.max.x + 1, if edge == Edge::Top { 1 } else { -1 });
+
+            // Once every claim starting or stopping on this row is counted,
+            // the rows down to the next edge are all alike
+            if self.events.get(at + 1).is_some_and(|&(next, _, _)| next == y) {
+                continue;
+            }
+            if let Some((x, count)) = depth.deepest() {
+                if deepest.is_none_or(|(_, most)| count > most) {
+                    deepest = Some((Point::new(x, y), count));
+                }
+            }
+        }
+
+        deepest
+    }
+}
+
+/// The claims in order of their left edges, and where each one is in that
+/// order.
+#[derive(Debug, Clone)]
+struct ByLeft {
+    by_left: Vec<usize>,
+    lefts: Vec<i64>,
+    place: Vec<usize>,
+}
+
+impl ByLeft {
+    fn new(claims: &[Claim]) -> ByLeft {
+        let mut by_left = (0..claims.len()).collect::<Vec<usize>>();
+        by_left.sort_by_key(|&i| claims[i].rect.min.x);
+        let lefts = by_left.iter().map(|&i| claims[i].rect.min.x).collect();
+        let mut place = vec![0; claims.len()];
+        for (at, &i) in by_left.iter().enumerate() {
+            place[i] = at;
+        }
+
+        ByLeft { by_left, lefts, place }
+    }
+}
+
+/// How much of a row is covered by at least one claim and by two, kept up
+/// as claims start and stop. A segment tree over the gaps between the
+/// claims' left and right edges.
+struct Coverage {
+    edges: Vec<i64>,
+    /// How many claims cover each node's whole span, without counting
+    /// those only covering part of it
+    count: Vec<u32>,
+    once: Vec<i64>,
+    twice: Vec<i64>,
+}
+
+impl Coverage {
+    fn new(claims: &[Claim]) -> Coverage {
+        let mut edges = claims.iter()
+            .flat_map(|claim| vec![claim.rect.min.x, claim.rect.max.x + 1])
+            .collect::<Vec<i64>>();
+        edges.sort_unstable();
+        edges.dedup();
+
+        let nodes = 4 * edges.len().max(1);
+        Coverage { edges, count: vec![0; nodes], once: vec![0; nodes], twice: vec![0; nodes] }
+    }
+
+    /// Counts a claim in or out over the columns from `left` up to `right`.
+    fn add(&mut self, left: i64, right: i64, delta: i32) {
+        let from = self.edges.partition_point(|&e| e < left);
+        let to = self.edges.partition_point(|&e| e < right);
+        if self.edges.len() > 1 {
+            self.update(1, 0, self.edges.len() - 1, from, to, delta);
+        }
+    }
+
+    /// Node `node` spans the gaps `lo..hi`; `from..to` is being changed.
+    fn update(&mut self, node: usize, lo: usize, hi: usize, from: usize, to: usize, delta: i32) {
+        if to <= lo || hi <= from {
+            return;
+        }
+
+        if from <= lo && hi <= to {
+            self.count[node] = (self.count[node] as i32 + delta) as u32;
+        } else {
+            let mid = (lo + hi) / 2;
+            self.update(2 * node, lo, mid, from, to, delta);
+            self.update(2 * node + 1, mid, hi, from, to, delta);
+        }
+
+        let width = self.edges[hi] - self.edges[lo];
+        let leaf = hi - lo == 1;
+        let (left, right) = (2 * node, 2 * node + 1);
+        let children = |v: &Vec<i64>| if leaf { 0 } else { v[left] + v[right] };
+
+        self.once[node] = if self.count[node] > 0 { width } else { children(&self.once) };
+        self.twice[node] = match self.count[node] {
+            0 => children(&self.twice),
+            1 => children(&self.once),
+            _ => width,
+        };
+    }
+}
+
+/// The rightmost edge of a set of claims, with the claims kept in order of
+/// their left edges: a segment tree of maximums.
+struct Rightmost {
+    len: usize,
+    max: Vec<Option<i64>>,
+}
+
+impl Rightmost {
+    fn new(len: usize) -> Rightmost {
+        Rightmost { len, max: vec![None; 4 * len.max(1)] }
+    }
+
+    fn set(&mut self, at: usize, right: Option<i64>) {
+        let (mut node, mut lo, mut hi) = (1, 0, self.len);
+        let mut path = Vec::new();
+        while hi - lo > 1 {
+            path.push(node);
+            let mid = (lo + hi) / 2;
+            if at < mid {
+                node *= 2;
+                hi = mid;
+            } else {
+                node = 2 * node + 1;
+                lo = mid;
+            }
+        }
+
+        self.max[node] = right;
+        for &node in path.iter().rev() {
+            self.max[node] = self.max[2 * node].max(self.max[2 * node + 1]);
+        }
+    }
+
+    /// One of the first `upto` claims reaching at least as far right as
+    /// `right`, if there is one.
+    fn find(&self, upto: usize, right: i64) -> Option<usize> {
+        self.search(1, 0, self.len, upto, right)
+    }
+
+    /// Every one of the first `upto` claims reaching at least as far right
+    /// as `right`.
+    fn find_all(&self, upto: usize, right: i64) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.gather(1, 0, self.len, upto, right, &mut found);
+        found
+    }
+
+    fn gather(&self, node: usize, lo: usize, hi: usize, upto: usize, right: i64, found: &mut Vec<usize>) {
+        if lo >= upto || self.max[node].is_none_or(|max| max < right) {
+            return;
+        }
+        if hi - lo == 1 {
+            found.push(lo);
+            return;
+        }
+
+        let mid = (lo + hi) / 2;
+        self.gather(2 * node, lo, mid, upto, right, found);
+        self.gather(2 * node + 1, mid, hi, upto, right, found);
+    }
+
+    fn search(&self, node: usize, lo: usize, hi: usize, upto: usize, right: i64) -> Option<usize> {
+        if lo >= upto || self.max[node].is_none_or(|max| max < right) {
+            return None;
+        }
+        if hi - lo == 1 {
+            return Some(lo);
+        }
+
+        let mid = (lo + hi) / 2;
+        self.search(2 * node, lo, mid, upto, right)
+            .or_else(|| self.search(2 * node + 1, mid, hi, upto, right))
+    }
+}
+
+/// How many claims cover each column of a row, kept up as claims start and
+/// stop, to find the most covered: a segment tree over the gaps between the
+/// claims' edges, with each node's count added to everything under it.
+struct Depth {
+    edges: Vec<i64>,
+    added: Vec<i32>,
+    /// The most under each node, and its leftmost gap
+    most: Vec<(i32, usize)>,
+}
+
+impl Depth {
+    fn new(claims: &[Claim]) -> Depth {
+        let mut edges = claims.iter()
+            .flat_map(|claim| vec![claim.rect.min.x, claim.rect.max.x + 1])
+            .collect::<Vec<i64>>();
+        edges.sort_unstable();
+        edges.dedup();
+
+        let gaps = edges.len().saturating_sub(1).max(1);
+        let mut depth = Depth { edges, added: vec![0; 4 * gaps], most: vec![(0, 0); 4 * gaps] };
+        depth.build(1, 0, gaps);
+        depth
+    }
+
+    fn build(&mut self, node: usize, lo: usize, hi: usize) {
+        self.most[node] = (0, lo);
+        if hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            self.build(2 * node, lo, mid);
+            self.build(2 * node + 1, mid, hi);
+        }
+    }
+
+    fn add(&mut self, left: i64, right: i64, delta: i32) {
+        let from = self.edges.partition_point(|&e| e < left);
+        let to = self.edges.partition_point(|&e| e < right);
+        if self.edges.len() > 1 {
+            self.update(1, 0, self.edges.len() - 1, from, to, delta);
+        }
+    }
+
+    fn update(&mut self, node: usize, lo: usize, hi: usize, from: usize, to: usize, delta: i32) {
+        if to <= lo || hi <= from {
+            return;
+        }
+
+        if from <= lo && hi <= to {
+            self.added[node] += delta;
+            self.most[node].0 += delta;
+            return;
+        }
+
+        let mid = (lo + hi) / 2;
+        self.update(2 * node, lo, mid, from, to, delta);
+        self.update(2 * node + 1, mid, hi, from, to, delta);
+
+        // Ties go left, to the first column
+        let (left, right) = (self.most[2 * node], self.most[2 * node + 1]);
+        let (count, gap) = if right.0 > left.0 { right } else { left };
+        self.most[node] = (count + self.added[node], gap);
+    }
+
+    /// The first column with the most claims on it, and how many, unless
+    /// there are none.
+    fn deepest(&self) -> Option<(i64, usize)> {
+        match self.most[1] {
+            (count, gap) if count > 0 => Some((self.edges[gap], count as usize)),
+            _ => None,
+        }
+    }
+}
+
+/// A claim's square inches: `min` is the top left one and `max` the bottom
+/// right one.
+pub type Rectangle = Bounds;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Claim {
+    pub id: usize,
+    pub rect: Rectangle,
+}
+
+const CLAIM: &str = "a claim like `#1 @ 1,3: 4x4`";
+
+impl FromStr for Claim {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Claim> {
+        let err = |column, text: &str| Error::parse(Day03::DAY, column, text, CLAIM);
+
+        let fields = parse::fields(s);
+        let (id, origin, size) = match fields.as_slice() {
+            [id, (_, "@"), origin, size] => (id, origin, size),
+            _ => return Err(err(1, s)),
+        };
+
+        let id = id.1.strip_prefix('#')
+            .and_then(|i| i.parse().ok())
+            .ok_or_else(|| err(id.0, id.1))?;
+
+        let (x, y) = origin.1.strip_suffix(':')
+            .and_then(|o| parse::pair::<u32>(o, ','))
+            .ok_or_else(|| err(origin.0, origin.1))?;
+
+        let (w, h) = parse::pair::<u32>(size.1, 'x')
+            .filter(|&(w, h)| w > 0 && h > 0)
+            .ok_or_else(|| err(size.0, size.1))?;
+
+        let min = Point::new(x.into(), y.into());
+        let size = Point::new(i64::from(w) - 1, i64::from(h) - 1);
+
+        Ok(Claim {
+            id,
+            rect: Rectangle::new(min, min + size),
+        })
+    }
+}
+
+impl Claim {
+    pub fn overlaps(&self, other: &Claim) -> bool {
+        self.overlap(other).is_some()
+    }
+
+    /// The square inches both claims cover, if there are any.
+    pub fn overlap(&self, other: &Claim) -> Option<Rectangle> {
+        self.rect.intersection(&other.rect)
+    }
+}
+
+impl fmt::Display for Claim {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Rectangle { min, .. } = self.rect;
+        write!(f, "#{} @ {},{}: {}x{}", self.id, min.x, min.y, self.rect.width(), self.rect.height())
+    }
+}
+
+#[test]
+fn claim_from_str() -> Result<()> {
+    let claim = Claim::from_str("#123 @ 3,2: 5x4")?;
+    assert_eq!(claim.id, 123);
+    assert_eq!(claim.rect, Rectangle::new(Point::new(3, 2), Point::new(7, 5)));
+
+    let err = Claim::from_str("#123 @ 3,x: 5x4").unwrap_err().to_string();
+    assert!(err.contains("column 8"), "{}", err);
+
+    assert!(Claim::from_str("#123 @ 3,2:").is_err());
+    Ok(())
+}
+
+#[test]
+fn fabric_agrees_with_counting_inches() {
+    let rng = &mut crate::gen::Rng::new(3);
+    for _ in 0..200 {
+        let claims = (1..rng.range(1, 12))
+            .map(|id| {
+                let min = Point::new(rng.range(0, 12), rng.range(0, 12));
+                let size = Point::new(rng.range(0, 6), rng.range(0, 6));
+                Claim { id: id as usize, rect: Rectangle::new(min, min + size) }
+            })
+            .collect::<Vec<Claim>>();
+
+        let fabric = Fabric::new(&claims);
+        assert_eq!(fabric.overlap_area(), reference::part1(&claims) as u128, "{:?}", claims);
+        let lonely = fabric.lonely().iter().map(|c| c.id).collect::<Vec<usize>>();
+        let expected = claims.iter()
+            .filter(|a| claims.iter().all(|b| a.id == b.id || a.rect.intersection(&b.rect).is_none()))
+            .map(|c| c.id)
+            .collect::<Vec<usize>>();
+        assert_eq!(lonely, expected, "{:?}", claims);
+
+        let pairs = fabric.overlaps().iter().map(|(a, b)| (a.id, b.id)).collect::<Vec<(usize, usize)>>();
+        let expected = claims.iter()
+            .flat_map(|a| claims.iter().filter(move |b| a.id < b.id && a.overlaps(b)).map(move |b| (a.id, b.id)))
+            .collect::<Vec<(usize, usize)>>();
+        assert_eq!(pairs, expected, "{:?}", claims);
+
+        let inches = reference::claimed(&claims);
+        let most = inches.values().max().copied();
+        let first = inches.iter()
+            .filter(|&(_, count)| Some(*count) == most)
+            .map(|(&(x, y), &count)| (Point::new(x, y), count))
+            .min();
+        assert_eq!(fabric.deepest(), first, "{:?}", claims);
+
+        for (i, claim) in claims.iter().enumerate() {
+            let others = fabric.overlapping(i).iter().map(|c| c.id).collect::<Vec<usize>>();
+            let expected = claims.iter().filter(|b| b.id != claim.id && b.overlaps(claim)).map(|c| c.id).collect::<Vec<usize>>();
+            assert_eq!(others, expected, "{:?}", claims);
+        }
+
+        // Spread each group out from its first claim, along the pairs
+        let mut expected: Vec<Vec<usize>> = Vec::new();
+        for claim in &claims {
+            if expected.iter().any(|group| group.contains(&claim.id)) {
+                continue;
+            }
+            let mut group = vec![claim.id];
+            let mut at = 0;
+            while at < group.len() {
+                for &(a, b) in &pairs {
+                    for &(from, to) in &[(a, b), (b, a)] {
+                        if from == group[at] && !group.contains(&to) {
+                            group.push(to);
+                        }
+                    }
+                }
+                at += 1;
+            }
+            group.sort_unstable();
+            expected.push(group);
+        }
+        let components = fabric.components().iter()
+            .map(|group| group.iter().map(|c| c.id).collect::<Vec<usize>>())
+            .collect::<Vec<Vec<usize>>>();
+        assert_eq!(components, expected, "{:?}", claims);
+    }
+}
+
+#[test]
+fn conflicts() -> Result<()> {
+    let claims = parse("#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n#4 @ 6,6: 3x1\n#5 @ 20,20: 1x1\n")?;
+    let fabric = Fabric::new(&claims);
+    let ids = |claims: &[&Claim]| claims.iter().map(|c| c.id).collect::<Vec<usize>>();
+
+    assert_eq!(ids(&fabric.overlapping(0)), vec![2]);
+    assert_eq!(ids(&fabric.overlapping(2)), vec![4]);
+    assert_eq!(claims[0].overlap(&claims[1]), Some(Rectangle::new(Point::new(3, 3), Point::new(4, 4))));
+    assert_eq!(claims[0].overlap(&claims[2]), None);
+
+    let components = fabric.components().iter().map(|group| ids(group)).collect::<Vec<Vec<usize>>>();
+    assert_eq!(components, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    assert_eq!(ids(&fabric.lonely()), vec![5]);
+    assert_eq!(fabric.deepest(), Some((Point::new(3, 3), 2)));
+    assert_eq!(Fabric::new(&[]).deepest(), None);
+
+    // A claim made twice overlaps its copy, but not itself
+    let claims = parse("#1 @ 1,1: 2x2\n#1 @ 1,1: 2x2\n")?;
+    let fabric = Fabric::new(&claims);
+    assert_eq!(ids(&fabric.overlapping(0)), vec![1]);
+    assert_eq!(ids(&fabric.overlapping(1)), vec![1]);
+    Ok(())
+}
+
+#[test]
+fn fabric_of_any_size() -> Result<()> {
+    let claims = parse("#1 @ 0,0: 4000000000x4000000000\n#2 @ 2000000000,2000000000: 4000000000x4000000000\n#3 @ 4294967295,0: 1x1\n")?;
+    let fabric = Fabric::new(&claims);
+    assert_eq!(fabric.overlap_area(), 2_000_000_000u128 * 2_000_000_000);
+    assert_eq!(fabric.claimed_area(), 2 * 16_000_000_000_000_000_000u128 - 4_000_000_000_000_000_000 + 1);
+    assert_eq!(fabric.lonely().iter().map(|c| c.id).collect::<Vec<usize>>(), vec![3]);
+    Ok(())
+}
@@ -0,0 +1,457 @@
+//! Day 4: Repose Record
+
+use crate::error::Error;
+use crate::parse;
+use crate::solution::{Answer, Result, Solution};
+use chrono::{NaiveDateTime, Timelike};
+use nom::{named, delimited, char, is_not};
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+pub fn parse(input: &str) -> Result<GuardDuty> {
+    let mut lines = parse::lines(input, |line| {
+        Record::from_str(line)?;
+        Ok(line.to_string())
+    })?;
+
+    lines.sort();
+
+    GuardDuty::from_log(&Log::build(&lines))
+}
+
+const DATE_FMT: &str = "%Y-%m-%d %H:%M";
+const TIMESTAMP: &str = "a timestamp like `[1518-11-01 00:00]`";
+const EVENT: &str = "`Guard #10 begins shift`, `falls asleep` or `wakes up`";
+
+pub fn part1(guard_duty: &GuardDuty) -> usize {
+    let sleepy_guard = guard_duty.sleepiest_guard();
+
+    let sleepy_guard_duty = guard_duty.by_guard(sleepy_guard);
+    let sleepy_minute = sleepy_guard_duty.sleepiest_minute();
+
+    sleepy_guard * sleepy_minute as usize
+}
+
+pub fn part2(guard_duty: &GuardDuty) -> usize {
+    let guard_map = guard_duty.guard_map_minutes();
+
+    let mut guard_minute: Vec<(usize, (u32, usize))> = Vec::new();
+    for (guard, minutes) in &guard_map {
+        guard_minute.push( (*guard, mode(minutes.clone())) );
+    }
+
+    let mut most = (0, (0, 0));
+    for (guard, (minute, count)) in guard_minute {
+        if count > (most.1).1 {
+            most = (guard, (minute, count));
+        }
+    }
+
+    let (guard, (minute, _)) = most;
+
+    guard * minute as usize
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Repose Record";
+
+    type Input = GuardDuty;
+
+    fn parse(input: &str) -> Result<GuardDuty> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<Error> {
+        parse::check_lines(input, Record::from_str)
+    }
+
+    fn part1(input: &GuardDuty) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &GuardDuty) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn parse_date_time(s: &str) -> Result<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(s, DATE_FMT)
+        .map_err(|_| Error::parse(Day04::DAY, 1, s, TIMESTAMP))
+}
+
+fn parse_dt_string(line: &str) -> Result<NaiveDateTime> {
+    let timestamp = parse_btw_brackets(line);
+    if timestamp.is_empty() {
+        return Err(Error::parse(Day04::DAY, 1, line, TIMESTAMP));
+    }
+
+    parse_date_time(&timestamp)
+}
+
+/// A single log line, like `[1518-11-01 00:00] Guard #10 begins shift`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub time: NaiveDateTime,
+    pub event: Event,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    BeginsShift(usize),
+    FallsAsleep,
+    WakesUp,
+}
+
+impl FromStr for Record {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Record> {
+        let time = parse_dt_string(line)?;
+
+        let fields = parse::fields(line);
+        let event = fields.iter()
+            .skip_while(|(_, f)| !f.ends_with(']'))
+            .skip(1)
+            .map(|(_, f)| *f)
+            .collect::<Vec<&str>>();
+
+        let event = match event.as_slice() {
+            ["falls", "asleep"] => Event::FallsAsleep,
+            ["wakes", "up"] => Event::WakesUp,
+            ["Guard", id, "begins", "shift"] => Event::BeginsShift(parse_guard(id)?),
+            _ => {
+                let column = fields.iter()
+                    .position(|(_, f)| f.ends_with(']'))
+                    .and_then(|i| fields.get(i + 1))
+                    .map(|(column, _)| *column)
+                    .unwrap_or(1);
+                let text = line.chars().skip(column - 1).collect::<String>();
+                return Err(Error::parse(Day04::DAY, column, &text, EVENT));
+            },
+        };
+
+        Ok(Record { time, event })
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}] ", self.time.format(DATE_FMT))?;
+        match self.event {
+            Event::BeginsShift(guard) => write!(f, "Guard #{} begins shift", guard),
+            Event::FallsAsleep => write!(f, "falls asleep"),
+            Event::WakesUp => write!(f, "wakes up"),
+        }
+    }
+}
+
+fn parse_guard(id: &str) -> Result<usize> {
+    id.strip_prefix('#')
+        .and_then(|i| i.parse().ok())
+        .ok_or_else(|| Error::parse(Day04::DAY, 1, id, "a guard ID like `#10`"))
+}
+
+#[derive(Debug, Clone)]
+pub struct ShiftLog(pub Vec<String>);
+
+impl ShiftLog {
+    pub fn to_shift(&self) -> Result<Shift> {
+        let (first, last) = match (self.0.first(), self.0.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Err(Error::invalid(Day04::DAY, "empty shift")),
+        };
+
+        let guard_split: Vec<&str> = first.split_whitespace().collect();
+        let guard = match guard_split.as_slice() {
+            [_, _, "Guard", id, "begins", "shift"] => parse_guard(id)?,
+            _ => return Err(Error::invalid(Day04::DAY,
+                format!("shift doesn't begin with a guard: `{}`", first)
+            )),
+        };
+
+        let start = parse_dt_string(first)?;
+        let end = parse_dt_string(last)?;
+
+        let nap_vec: Vec<&String> = self.0.iter().filter(|line| 
+            line.contains("asleep") || line.contains("wake")
+        ).collect();
+
+        let mut naps: Vec<Nap> = Vec::new();
+        for pair in nap_vec.chunks(2) {
+            match pair {
+                [asleep, awake] if asleep.contains("asleep") && awake.contains("wake") => {
+                    let start = parse_dt_string(asleep)?;
+                    let end = parse_dt_string(awake)?;
+                    naps.push(Nap { start, end });
+                },
+                _ => return Err(Error::invalid(Day04::DAY,
+                    format!("guard #{} doesn't fall asleep and wake up in turn: `{}`", guard, pair[0])
+                )),
+            }
+        }
+
+        Ok(Shift {
+            guard, start, end, naps
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Log(pub Vec<ShiftLog>);
+
+named!(in_brackets, delimited!(char!('['), is_not!("]"), char!(']')));
+
+fn parse_btw_brackets(line: &str) -> String {
+    std::str::from_utf8(
+        in_brackets(line.as_bytes())
+        .unwrap_or((&[0u8], &[0u8])).1
+    ).unwrap_or("")
+        .to_string()
+}
+
+impl Log {
+    pub fn build(lines: &[String]) -> Self {
+        let mut log = Log(Vec::new());
+
+        let mut shift_log = ShiftLog(Vec::new());
+        for line in lines.iter() {
+            if line == &lines[0] || !line.contains("begins shift") {
+                shift_log.0.push(line.clone());
+            } else {
+                shift_log.0.push(line.clone());
+                log.0.push(shift_log.clone());
+                shift_log.0.clear();
+                shift_log.0.push(line.clone());
+            }
+        }
+
+        if !shift_log.0.is_empty() {
+            log.0.push(shift_log);
+        }
+
+        log
+    }
+}
+
+#[derive(Debug)]
+pub struct GuardDuty(pub Vec<Shift>);
+
+impl GuardDuty {
+    pub fn from_log(log: &Log) -> Result<Self> {
+        let shifts = log.0.iter()
+            .map(|l| l.to_shift())
+            .collect::<Result<Vec<Shift>>>()?;
+
+        if shifts.is_empty() {
+            return Err(Error::invalid(Day04::DAY, "no complete guard shifts in the log"));
+        }
+
+        Ok(GuardDuty(shifts))
+    }
+
+    pub fn sleepiest_guard(&self) -> usize {
+        let mut guard_hash: BTreeMap<usize, i64> = BTreeMap::new();
+
+        for shift in &self.0 {
+            let guard = shift.guard;
+
+            let mut nap_sum = shift.naps.iter()
+                .map(|nap| nap.duration())
+                .sum();
+
+            nap_sum += guard_hash.get(&guard).unwrap_or(&0_i64);
+            guard_hash.insert(guard, nap_sum);
+        }
+
+        *guard_hash.iter()
+            .filter(|(_, v)|
+                *v == guard_hash.values()
+                    .max()
+                    .expect("Guard Hash is empty")
+            ).map(|(k, _)| k)
+            .next_back()
+            .expect("Guard Hash is empty")
+    }
+
+    pub fn sleepiest_minute(&self) -> u32 {
+        let mut stack: BTreeMap<u32, usize> = BTreeMap::new();
+
+        for shift in &self.0 {
+            for nap in &shift.naps {
+                let start = nap.start.minute();
+                let end = nap.end.minute();
+                for minute in 0..60 {
+                    if minute >= start && minute < end {
+                        let count = *stack.get(&minute).unwrap_or(&0);
+                        stack.insert(minute, count + 1);
+                    }
+                }
+            }
+        }
+
+        let mut biggest = (0_u32, 0_usize);
+        for (minute, count) in &stack {
+            if *count > biggest.1 {
+                biggest = (*minute, *count);
+            }
+        }
+
+        // println!("Sleepiest Minute: {}", biggest.0);
+        biggest.0
+    }
+
+    pub fn guard_most_asleep_on_minute(&self, minute: u32) -> usize {
+        let mut guards: BTreeMap<usize, usize> = BTreeMap::new();
+
+        for shift in &self.0 {
+            for nap in &shift.naps {
+                if nap.contains_minute(minute) {
+                    let count = guards.get(&shift.guard).unwrap_or(&0_usize) + 1;
+                    guards.insert(shift.guard, count);
+                }
+            }
+        }
+
+        let mut biggest: (usize, usize) = (0, 0);
+        for (guard, count) in guards {
+            if count > biggest.1 {
+                biggest = (guard, count);
+            }
+        }
+
+        // println!("GUARD #{}: {}", biggest.0, biggest.1);
+        
+        biggest.0
+    }
+
+    pub fn by_guard(&self, guard: usize) -> Self {
+        let mut guard_duty = GuardDuty(Vec::new());
+
+        for shift in &self.0 {
+            if shift.guard == guard {
+                guard_duty.0.push(shift.clone());
+            }
+        }
+
+        guard_duty
+    }
+
+    pub fn guard_map_minutes(&self) -> BTreeMap<usize, Vec<u32>> {
+        let mut guard_map: BTreeMap<usize, Vec<u32>> = BTreeMap::new();
+        let dummy: Vec<u32> = Vec::new();
+
+        for shift in &self.0 {
+            let mut minute_vec = guard_map.get(&shift.guard).unwrap_or(&dummy).clone();
+            for nap in &shift.naps {
+                for minute in &nap.minutes(){
+                    minute_vec.push(*minute);
+                }
+            }
+            guard_map.insert(shift.guard, minute_vec);
+        }
+
+        guard_map
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Shift {
+    pub guard: usize,
+    pub start: NaiveDateTime,
+    pub end:   NaiveDateTime,
+    pub naps:  Vec<Nap>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Nap {
+    pub start: NaiveDateTime,
+    pub end:   NaiveDateTime,
+}
+
+impl Nap {
+    pub fn duration(&self) -> i64 {
+        let duration = self.end - self.start;
+        duration.num_minutes()
+    }
+
+    pub fn minutes(&self) -> Vec<u32> {
+        let mut minutes: Vec<u32> = Vec::new();        
+
+        for m in 0..60 {
+            if self.contains_minute(m) {
+                minutes.push(m);
+            }
+        }
+
+        minutes
+    }
+
+    pub fn contains_minute(&self, minute: u32) -> bool {
+        minute >= self.start.minute() && minute < self.end.minute()
+    }
+
+    pub fn print_timeline(&self) {
+        let mut timeline = String::new();
+
+        for minute in 0..60 {
+            if minute >= self.start.minute() && minute < self.end.minute(){
+                timeline.push('#');
+            } else {
+                timeline.push('.');
+            }
+        }
+
+        eprintln!("{}", timeline);
+    }
+}
+
+pub fn mode<T>(items: Vec<T>) -> (T, usize) 
+where T: Ord + Default {
+    let mut item_count: BTreeMap<T, usize> = BTreeMap::new();
+
+    for item in items {
+        if item_count.contains_key(&item) {
+            let count = item_count.get(&item).unwrap() + 1;
+            item_count.insert(item, count);
+        } else {
+            item_count.insert(item, 1);
+        }
+    }
+
+    let mut most = (T::default(), 0);
+    for (item, count) in item_count {
+        if count > most.1 {
+            most = (item, count);
+        }
+    }
+
+    most
+}
+
+#[test]
+fn bad_records() {
+    let input = "[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] falls over\n";
+    let err = parse(input).unwrap_err().to_string();
+    assert!(err.contains("line 2, column 20"), "{}", err);
+
+    let input = "[1518-11-01 00:00] Guard #10 begins shift\n[1518-13-01 00:05] falls asleep\n";
+    let err = parse(input).unwrap_err().to_string();
+    assert!(err.contains("line 2, column 1"), "{}", err);
+
+    let input = "[1518-11-01 00:05] falls asleep\n[1518-11-01 00:25] wakes up\n";
+    assert!(parse(input).is_err());
+}
+
+#[test]
+fn counts_the_last_shift() -> Result<()> {
+    let input = "[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] falls asleep\n\
+        [1518-11-01 00:10] wakes up\n[1518-11-02 00:00] Guard #99 begins shift\n\
+        [1518-11-02 00:20] falls asleep\n[1518-11-02 00:50] wakes up\n";
+    assert_eq!(part1(&parse(input)?), 99 * 20);
+    Ok(())
+}
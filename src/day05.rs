@@ -0,0 +1,177 @@
+//! Day 5: Alchemical Reduction
+
+use crate::budget;
+use crate::error::Error;
+use crate::reference::Reference;
+use crate::solution::{Answer, Result, Solution};
+
+pub fn parse(input: &str) -> Result<String> {
+    let polymer = input.trim();
+
+    if let Some((column, unit)) = polymer.chars()
+        .enumerate()
+        .find(|(_, c)| !c.is_ascii_alphabetic())
+    {
+        return Err(Error::parse(Day05::DAY, column + 1, &unit.to_string(), "a unit from `a` to `z` or `A` to `Z`")
+            .at_line(1));
+    }
+
+    Ok(polymer.to_string())
+}
+
+pub fn part1(input: &str) -> usize {
+    react_loop(input).len()
+}
+
+pub fn part2(input: &str) -> usize {
+    let mut shortest_polymer: (char, usize) = (char::default(), usize::MAX);
+    for unit in UNITS {
+        let new_string = remove_unit(input, *unit);
+        let reacted_string = react_loop(&new_string);
+        if reacted_string.len() < shortest_polymer.1 {
+            shortest_polymer = (*unit, reacted_string.len())
+        }
+    }
+
+    shortest_polymer.1
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Alchemical Reduction";
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String> {
+        parse(input)
+    }
+
+    fn part1(input: &String) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &String) -> Answer {
+        part2(input).into()
+    }
+}
+
+impl Reference for Day05 {
+    fn part1(input: &String) -> Answer {
+        reference::part1(input).into()
+    }
+
+    fn part2(input: &String) -> Answer {
+        reference::part2(input).into()
+    }
+}
+
+/// Deliberately simple versions of both parts, to check the ones above
+/// against.
+pub mod reference {
+    use crate::budget;
+
+    fn reacts(a: char, b: char) -> bool {
+        a != b && a.eq_ignore_ascii_case(&b)
+    }
+
+    /// Takes out every reacting pair it comes across, over and over until a
+    /// pass finds none.
+    pub fn part1(polymer: &str) -> usize {
+        let mut units = polymer.chars().collect::<Vec<char>>();
+
+        loop {
+            let mut reacted = Vec::new();
+            let mut i = 0;
+            while i < units.len() {
+                if i + 1 < units.len() && reacts(units[i], units[i + 1]) {
+                    i += 2;
+                } else {
+                    reacted.push(units[i]);
+                    i += 1;
+                }
+            }
+
+            if reacted.len() == units.len() || !budget::step() {
+                return reacted.len();
+            }
+            units = reacted;
+        }
+    }
+
+    pub fn part2(polymer: &str) -> usize {
+        ('a'..='z')
+            .map(|unit| {
+                let without = polymer.chars()
+                    .filter(|c| !c.eq_ignore_ascii_case(&unit))
+                    .collect::<String>();
+                part1(&without)
+            })
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+pub const UNITS: &[char] = &[
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+    'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z'
+];
+
+pub fn react(string: &str) -> String {
+    let len = string.len();
+    let mut new_string = String::new();
+
+    let mut skip_next = false;
+    for (index, ch) in string.char_indices() {
+        if !skip_next {
+            if index + 2 > len { 
+                new_string.push(ch);
+                continue;
+            } else {
+                // eprintln!("{}: {}", index, &file_as_string[index..index + 2]);
+                match &string[index .. index + 2] {
+                    "Aa" | "Bb" | "Cc" | "Dd" | "Ee" | "Ff" | "Gg" | "Hh" |
+                    "Ii" | "Jj" | "Kk" | "Ll" | "Mm" | "Nn" | "Oo" | "Pp" |
+                    "Qq" | "Rr" | "Ss" | "Tt" | "Uu" | "Vv" | "Ww" | "Xx" |
+                    "Yy" | "Zz" | "aA" | "bB" | "cC" | "dD" | "eE" | "fF" |
+                    "gG" | "hH" | "iI" | "jJ" | "kK" | "lL" | "mM" | "nN" |
+                    "oO" | "pP" | "qQ" | "rR" | "sS" | "tT" | "uU" | "vV" |
+                    "wW" | "xX" | "yY" | "zZ" => skip_next = true,
+                    _ => {
+                        new_string.push(ch);
+                        skip_next = false;
+                    }
+                }
+            }
+        } else {
+            skip_next = false;
+        }
+    }
+
+    new_string
+}
+
+pub fn react_loop(file_as_string: &str) -> String {
+    let mut new_string = react(file_as_string);
+    let mut cmp_string = file_as_string.to_string();
+
+    while new_string != cmp_string && budget::step() {
+        cmp_string = react(&new_string);
+        new_string = react(&cmp_string);
+    }
+
+    new_string
+}
+
+pub fn remove_unit(string: &str, unit: char) -> String {
+    let mut new_string = String::new();
+
+    for character in string.chars() {
+        if character.to_ascii_uppercase() != unit {
+            new_string.push(character);
+        }
+    }
+
+    new_string
+}
@@ -0,0 +1,246 @@
+//! Day 6: Chronal Coordinates
+
+use crate::error::Error;
+use crate::grid::{self, Bounds, Point};
+use crate::parse;
+use crate::reference::Reference;
+use crate::solution::{Answer, Result, Solution};
+
+use std::collections::{HashMap, HashSet};
+
+pub fn parse(input: &str) -> Result<Grid> {
+    let coords = parse::lines(input, coord)?;
+
+    Grid::new(coords)
+}
+
+/// The largest finite area, or 0 if every area is infinite.
+pub fn part1(grid: &Grid) -> usize {
+    let infinite = grid.infinite();
+
+    grid.area_map().iter()
+        .filter(|(coord, _)| !infinite.contains(coord))
+        .map(|(_, &count)| count)
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn part2(grid: &Grid) -> usize {
+    let area_map = grid.area_map();
+
+    grid.coords.iter()
+        .map(|coord| (coord, grid.sum_distance(*coord)) )
+        .filter(|(_, d)| d < &10000)
+        .map(|(c, _)| c)
+        .filter_map(|coord| area_map.get(coord))
+        .sum()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Chronal Coordinates";
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Grid> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<Error> {
+        parse::check_lines(input, coord)
+    }
+
+    fn part1(input: &Grid) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Grid) -> Answer {
+        part2(input).into()
+    }
+}
+
+impl Reference for Day06 {
+    fn part1(input: &Grid) -> Answer {
+        reference::part1(input).into()
+    }
+
+    fn part2(input: &Grid) -> Answer {
+        reference::part2(input).into()
+    }
+}
+
+/// Deliberately simple versions of both parts, to check the ones above
+/// against.
+pub mod reference {
+    use super::{Coord, Grid};
+    use crate::grid::Bounds;
+
+    /// The largest area closest to one coordinate that doesn't reach the
+    /// edge of the box around them all.
+    pub fn part1(grid: &Grid) -> usize {
+        let coords = &grid.coords;
+        let Bounds { min, max } = Bounds::of(coords.iter().copied()).expect("no coordinates");
+
+        let mut areas = vec![0; coords.len()];
+        let mut infinite = vec![false; coords.len()];
+
+        for x in min.x..=max.x {
+            for y in min.y..=max.y {
+                let point = Coord { x, y };
+                let distances = coords.iter().map(|c| c.manhattan(point)).collect::<Vec<i64>>();
+                let nearest = *distances.iter().min().unwrap();
+                let closest = (0..coords.len()).filter(|&i| distances[i] == nearest).collect::<Vec<usize>>();
+
+                if let [only] = closest.as_slice() {
+                    areas[*only] += 1;
+                    if x == min.x || x == max.x || y == min.y || y == max.y {
+                        infinite[*only] = true;
+                    }
+                }
+            }
+        }
+
+        (0..coords.len()).filter(|&i| !infinite[i]).map(|i| areas[i]).max().unwrap_or(0)
+    }
+
+    /// Points less than 10000 from all the coordinates put together. None
+    /// of them can be further outside the box than 10000 over the number
+    /// of coordinates.
+    pub fn part2(grid: &Grid) -> usize {
+        let coords = &grid.coords;
+        let margin = 10_000 / coords.len() as i64 + 1;
+        let Bounds { min, max } = Bounds::of(coords.iter().copied()).expect("no coordinates");
+
+        let mut region = 0;
+        for x in min.x - margin..=max.x + margin {
+            for y in min.y - margin..=max.y + margin {
+                let total = coords.iter().map(|c| c.manhattan(Coord { x, y })).sum::<i64>();
+                if total < 10_000 {
+                    region += 1;
+                }
+            }
+        }
+        region
+    }
+}
+
+pub type Coord = Point;
+
+/// Parses `1, 6`; coordinates display as `1,6`, which parses too.
+pub fn coord(s: &str) -> Result<Coord> {
+    let (x, y) = parse::pair(s, ',')
+        .ok_or_else(|| Error::parse(Day06::DAY, 1, s, "a coordinate like `1, 6`"))?;
+
+    Ok(Coord { x, y })
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    pub coords: Vec<Coord>,
+    /// The index in `coords` of the coordinate closest to each point, or
+    /// `None` where two or more are as close
+    pub closest: grid::Grid<Option<usize>>,
+}
+
+impl Grid {
+    pub fn new(coords: Vec<Coord>) -> Result<Grid> {
+        let bounds = Bounds::of(coords.iter().copied())
+            .ok_or_else(|| Error::invalid(Day06::DAY, "no coordinates"))?
+            .limit(Day06::DAY)?;
+
+        let closest = grid::Grid::from_fn(bounds, |point| {
+            match closest_coords(&coords, point).0.as_slice() {
+                [only] => coords.iter().position(|c| c == only),
+                _ => None,
+            }
+        });
+
+        Ok(Grid { coords, closest })
+    }
+
+    pub fn closest_coords(&self, coord: Coord) -> (Vec<Coord>, i64) {
+        closest_coords(&self.coords, coord)
+    }
+
+    pub fn sum_distance(&self, coord: Coord) -> i64 {
+        self.coords.iter().map(|c| coord.manhattan(*c)).sum()
+    }
+
+    pub fn area_map(&self) -> HashMap<Coord, usize> {
+        let mut area_map = HashMap::new();
+
+        for index in self.closest.values().flatten() {
+            *area_map.entry(self.coords[*index]).or_insert(0) += 1;
+        }
+
+        area_map
+    }
+
+    /// The coordinates closest to a point on the edge, whose areas go on
+    /// forever.
+    pub fn infinite(&self) -> HashSet<Coord> {
+        let bounds = self.closest.bounds();
+
+        self.closest.iter()
+            .filter(|(point, _)| bounds.on_edge(*point))
+            .filter_map(|(_, index)| *index)
+            .map(|index| self.coords[index])
+            .collect()
+    }
+}
+
+fn closest_coords(coords: &[Coord], point: Coord) -> (Vec<Coord>, i64) {
+    let mut closest = (Vec::new(), i64::MAX);
+
+    for loc in coords {
+        let distance = point.manhattan(*loc);
+        if distance < closest.1 {
+            closest = (vec![*loc], distance);
+        } else if distance == closest.1 {
+            closest.0.push(*loc);
+        }
+    }
+
+    closest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stringer() -> Result<()> {
+        let s = "1, 2";
+        assert_eq!(
+            coord(s)?,
+            Coord { x: 1, y: 2}
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn closer() -> Result<()> {
+        let grid = Grid::new(
+            vec![
+                Coord {x: 1, y: 1}, Coord {x: 1, y: 6},
+                Coord {x: 8, y: 3}, Coord {x: 3, y: 4},
+                Coord {x: 5, y: 5}, Coord {x: 8, y: 9},
+            ]
+        )?;
+
+        let closest = grid.closest_coords(Coord{x: 0, y: 0});
+        assert!(
+            closest.0.contains(&Coord{x: 1, y: 1})
+        );
+
+        let closest2 = grid.closest_coords(Coord{x: 3, y: 9});
+        assert!(
+            closest2.0.contains(&Coord{x: 1, y: 6}) &&
+            closest2.0.contains(&Coord{x: 8, y: 9})
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+//! Day 7: The Sum of Its Parts
+
+use crate::error::Error;
+use crate::parse;
+use crate::reference::Reference;
+use crate::solution::{Answer, Result, Solution};
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::fmt;
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>> {
+    let instructions = parse::lines(input, Instruction::from_str)?;
+
+    if instructions.is_empty() {
+        return Err(Error::invalid(Day07::DAY, "no instructions"));
+    }
+
+    Ok(instructions)
+}
+
+pub fn part1(instructions: &[Instruction]) -> String {
+    let mut order = Vec::<Name>::new();
+    // let mut order_queue =  Vec::<Vec<Name>>::new();
+
+    let prereq_map = Instruction::prereq_map(instructions);
+    let mut status_map = StatusMap::new(&prereq_map);
+
+    // Get initial instructions without prerequisites
+    for prereqs in prereq_map.values() {
+        for p in prereqs {
+            if ! prereq_map.contains_key(p) && ! order.contains(p)  {
+                status_map.mark_done(p);
+                order.push(*p);
+            }
+        }
+    }
+
+    assert!( ! prereq_map.values().all(|v| v.is_empty()) );
+
+    while status_map.is_not_done() {
+        let done = order.len();
+        for (name, prereqs) in &prereq_map {
+            if status_map.satisfied(prereqs) && !order.contains(name){
+                status_map.mark_done(name);
+                order.push(*name);
+            }
+        }
+
+        // Steps waiting on each other never get done
+        if order.len() == done {
+            break;
+        }
+    }
+
+    order.iter().collect()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "The Sum of Its Parts";
+    const PARTS: u8 = 1;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<Error> {
+        parse::check_lines(input, Instruction::from_str)
+    }
+
+    fn part1(input: &Vec<Instruction>) -> Answer {
+        part1(input).into()
+    }
+}
+
+impl Reference for Day07 {
+    fn part1(input: &Vec<Instruction>) -> Answer {
+        reference::part1(input).into()
+    }
+}
+
+/// A deliberately simple version of part 1, to check the one above against.
+pub mod reference {
+    use super::Instruction;
+    use std::collections::BTreeSet;
+
+    /// Does whichever step is first alphabetically of those ready, one at a
+    /// time.
+    pub fn part1(instructions: &[Instruction]) -> String {
+        let mut steps = instructions.iter()
+            .flat_map(|i| vec![i.name, i.prereq])
+            .collect::<BTreeSet<char>>();
+        let mut done = String::new();
+
+        while let Some(&next) = steps.iter().find(|&&step| {
+            instructions.iter().all(|i| i.name != step || done.contains(i.prereq))
+        }) {
+            done.push(next);
+            steps.remove(&next);
+        }
+
+        done
+    }
+}
+
+pub type Name = char;
+pub type PreReq = char;
+
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Instruction {
+    pub name: Name,
+    pub prereq: PreReq,
+}
+
+pub type PrereqMap = BTreeMap<Name, Vec<PreReq>>;
+
+impl Instruction {
+    pub fn prereq_map(instructions: &[Instruction]) -> PrereqMap {
+        let mut prereq_map = PrereqMap::new();
+
+        for i in instructions {
+            prereq_map
+                .entry(i.name)
+                .or_default()
+                .push(i.prereq);
+        }
+
+        for prereqs in prereq_map.values_mut() {
+            prereqs.sort();
+        }
+
+        prereq_map
+    }
+}
+
+const INSTRUCTION: &str = "an instruction like `Step C must be finished before step A can begin.`";
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Instruction> {
+        let step = |(column, field): &(usize, &str)| {
+            let mut chars = field.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(c),
+                _ => Err(Error::parse(Day07::DAY, *column, field, "a single letter step name")),
+            }
+        };
+
+        let split = parse::fields(s);
+        match split.as_slice() {
+            [
+                (_, "Step"), prereq, (_, "must"), (_, "be"), (_, "finished"),
+                (_, "before"), (_, "step"), name, (_, "can"), (_, "begin.")
+            ] => Ok(Instruction { name: step(name)?, prereq: step(prereq)? }),
+            _ => Err(Error::parse(Day07::DAY, 1, s, INSTRUCTION)),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Progress {
+    NotStarted,
+    // InProgress,
+    Done,
+}
+
+#[derive(Debug)]
+pub struct StatusMap(pub BTreeMap<Name, Progress>);
+
+impl StatusMap {
+    pub fn new(prereq_map: &PrereqMap) -> StatusMap {
+        let mut status_map = BTreeMap::new();
+        
+        for (name, prereqs) in prereq_map {
+            status_map.insert(*name, Progress::NotStarted);
+            for p in prereqs {
+                status_map.insert(*p, Progress::NotStarted);
+            }
+        }
+
+        StatusMap(status_map)
+    }
+
+    pub fn mark_done(&mut self, name: &Name) {
+        if self.0.contains_key(name) {
+            self.0.insert(*name, Progress::Done);
+        } else {
+            panic!("Status map does not contain key '{}'", name);
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.0.values().all(|s| *s == Progress::Done)
+    }
+
+    pub fn is_not_done(&self) -> bool {
+        !self.is_done()
+    }
+
+    pub fn did(&self, name: &Name) -> bool {
+        self.0.contains_key(name) &&
+        *self.0.get(name)
+            .expect("StatusMap does not contain key. Cannot check if done")
+            == Progress::Done
+    }
+
+    pub fn did_not_do(&self, name: &Name) -> bool {
+        !self.did(name)
+    }
+
+    pub fn satisfied(&self, names: &[PreReq]) -> bool {
+        names.iter().all(|n| self.did(n))
+    }
+
+    pub fn unsatisfied(&self, names: &[PreReq]) -> bool {
+        !self.satisfied(names)
+    }
+}
+
+impl fmt::Display for StatusMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut format = String::new();
+        for (key, value) in &self.0 {
+            format.push_str(format!("\t{}: {:?}\n", key, value).as_str());
+        }
+        write!(f, "\nStatusMap{{\n{}}}\n", format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn all_done() {
+        let prereqs = &['A', 'B', 'C'];
+        let mut status_map = StatusMap(BTreeMap::new());
+        status_map.0.insert('A', Progress::Done);
+        status_map.0.insert('B', Progress::Done);
+        status_map.0.insert('C', Progress::Done);
+
+        assert!(status_map.is_done());
+        assert!(status_map.satisfied(prereqs));
+
+        let prereqs = &['1', '2', '3'];
+        let mut status_map = StatusMap(BTreeMap::new());
+        status_map.0.insert('1', Progress::Done);
+        status_map.0.insert('2', Progress::NotStarted);
+        status_map.0.insert('3', Progress::Done);
+
+        assert!(status_map.unsatisfied(prereqs));
+        assert!(status_map.is_not_done());
+
+        status_map.mark_done(&'2');
+
+        assert!(status_map.satisfied(prereqs));
+        assert!(status_map.is_done());
+    }
+    #[test]
+    fn instruction_from_str() -> Result<()> {
+        let instruction = Instruction::from_str("Step C must be finished before step A can begin.")?;
+        assert_eq!(instruction, Instruction { name: 'A', prereq: 'C' });
+
+        let err = Instruction::from_str("Step CD must be finished before step A can begin.")
+            .unwrap_err();
+        assert!(err.to_string().contains("column 6"), "{}", err);
+
+        assert!(Instruction::from_str("Step C must be finished").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+//! Day 8: Memory Maneuver
+
+use crate::error::Error;
+use crate::parse;
+use crate::solution::{Answer, Result, Solution};
+
+pub fn parse(input: &str) -> Result<Node> {
+    let mut numbers = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        for (column, text) in parse::fields(line) {
+            let number = text.parse::<usize>()
+                .map_err(|_| Error::parse(Day08::DAY, column, text, "a whole number").at_line(index + 1))?;
+            numbers.push(number);
+        }
+    }
+
+    let mut numbers = numbers.into_iter();
+    let root = Node::read(&mut numbers)?;
+
+    if numbers.next().is_some() {
+        return Err(Error::invalid(Day08::DAY, "numbers left over after the root node"));
+    }
+
+    Ok(root)
+}
+
+pub fn part1(root: &Node) -> usize {
+    root.metadata_sum()
+}
+
+pub fn part2(root: &Node) -> usize {
+    root.value()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Memory Maneuver";
+
+    type Input = Node;
+
+    fn parse(input: &str) -> Result<Node> {
+        parse(input)
+    }
+
+    fn part1(input: &Node) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Node) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn truncated() -> Error {
+    Error::invalid(Day08::DAY, "the license ends in the middle of a node")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub children: Vec<Node>,
+    pub metadata: Vec<usize>,
+}
+
+impl Node {
+    /// Reads a header, the children and then the metadata.
+    pub fn read<I: Iterator<Item = usize>>(numbers: &mut I) -> Result<Node> {
+        let child_count = numbers.next().ok_or_else(truncated)?;
+        let metadata_count = numbers.next().ok_or_else(truncated)?;
+
+        let children = (0..child_count)
+            .map(|_| Node::read(numbers))
+            .collect::<Result<Vec<Node>>>()?;
+
+        let metadata = numbers.by_ref().take(metadata_count).collect::<Vec<usize>>();
+        if metadata.len() < metadata_count {
+            return Err(truncated());
+        }
+
+        Ok(Node { children, metadata })
+    }
+
+    /// Saturates rather than overflowing on absurdly big metadata.
+    pub fn metadata_sum(&self) -> usize {
+        self.metadata.iter().copied()
+            .chain(self.children.iter().map(Node::metadata_sum))
+            .fold(0, usize::saturating_add)
+    }
+
+    /// A leaf is worth its metadata. Otherwise the metadata picks children,
+    /// counting from 1, and the node is worth theirs. Saturates like
+    /// [`Node::metadata_sum`].
+    pub fn value(&self) -> usize {
+        if self.children.is_empty() {
+            return self.metadata.iter().copied().fold(0, usize::saturating_add);
+        }
+
+        self.metadata.iter()
+            .filter_map(|&m| m.checked_sub(1).and_then(|i| self.children.get(i)))
+            .map(Node::value)
+            .fold(0, usize::saturating_add)
+    }
+}
+
+#[test]
+fn license() -> Result<()> {
+    let root = parse("2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2")?;
+    assert_eq!(part1(&root), 138);
+    assert_eq!(part2(&root), 66);
+
+    assert!(parse("2 3 0 3 10 11 12 1 1 0 1 99 2 1 1").is_err());
+    assert!(parse("0 1 7 1").is_err());
+    Ok(())
+}
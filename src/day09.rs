@@ -0,0 +1,165 @@
+//! Day 9: Marble Mania
+
+use crate::error::Error;
+use crate::parse;
+use crate::solution::{Answer, Result, Solution};
+
+const GAME: &str = "a game like `10 players; last marble is worth 1618 points`";
+
+/// The most players, and points for the last marble, a game can have. Part
+/// 2 plays a hundred times as many marbles, each taking room in the circle.
+pub const MOST: usize = 100_000;
+
+pub fn parse(input: &str) -> Result<Game> {
+    let line = input.lines()
+        .position(|l| !l.trim().is_empty())
+        .ok_or_else(|| Error::invalid(Day09::DAY, "no game"))?;
+    let text = input.lines().nth(line).unwrap_or_default();
+
+    let err = |column, text: &str| Error::parse(Day09::DAY, column, text, GAME).at_line(line + 1);
+    let fields = parse::fields(text);
+
+    match fields.as_slice() {
+        [players, (_, "players;"), (_, "last"), (_, "marble"), (_, "is"), (_, "worth"), last, (_, "points")] => {
+            let number = |(column, text): &(usize, &str)| text.parse::<usize>()
+                .ok()
+                .filter(|&n| n > 0)
+                .ok_or_else(|| err(*column, text));
+
+            let game = Game { players: number(players)?, last_marble: number(last)? };
+            if game.players > MOST || game.last_marble > MOST {
+                return Err(Error::invalid(Day09::DAY, format!("games go up to {} players and {} points", MOST, MOST)));
+            }
+
+            Ok(game)
+        },
+        _ => Err(err(1, text)),
+    }
+}
+
+pub fn part1(game: &Game) -> usize {
+    game.high_score()
+}
+
+pub fn part2(game: &Game) -> usize {
+    Game { last_marble: game.last_marble * 100, ..*game }.high_score()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Marble Mania";
+
+    type Input = Game;
+
+    fn parse(input: &str) -> Result<Game> {
+        parse(input)
+    }
+
+    fn part1(input: &Game) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Game) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Game {
+    pub players: usize,
+    pub last_marble: usize,
+}
+
+impl Game {
+    pub fn high_score(&self) -> usize {
+        let mut circle = Circle::new(self.last_marble + 1);
+        let mut scores = vec![0; self.players];
+
+        for marble in 1..=self.last_marble {
+            if marble % 23 == 0 {
+                circle.rotate_back(7);
+                scores[marble % self.players] += marble + circle.remove();
+            } else {
+                circle.rotate_forward(1);
+                circle.insert(marble);
+            }
+        }
+
+        scores.into_iter().max().unwrap_or(0)
+    }
+}
+
+/// The marbles in a ring, as a doubly linked list indexed by marble. The
+/// current marble is always the one just inserted, or the one after the last
+/// one removed.
+#[derive(Debug, Clone)]
+pub struct Circle {
+    next: Vec<usize>,
+    prev: Vec<usize>,
+    current: usize,
+}
+
+impl Circle {
+    /// A circle with room for marbles `0..capacity`, holding only marble 0.
+    pub fn new(capacity: usize) -> Circle {
+        Circle {
+            next: vec![0; capacity],
+            prev: vec![0; capacity],
+            current: 0,
+        }
+    }
+
+    pub fn rotate_forward(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.current = self.next[self.current];
+        }
+    }
+
+    pub fn rotate_back(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.current = self.prev[self.current];
+        }
+    }
+
+    /// Puts `marble` clockwise of the current marble and makes it current.
+    pub fn insert(&mut self, marble: usize) {
+        let after = self.next[self.current];
+        self.prev[marble] = self.current;
+        self.next[marble] = after;
+        self.next[self.current] = marble;
+        self.prev[after] = marble;
+        self.current = marble;
+    }
+
+    /// Takes out the current marble; the one clockwise of it becomes current.
+    pub fn remove(&mut self) -> usize {
+        let marble = self.current;
+        let (before, after) = (self.prev[marble], self.next[marble]);
+        self.next[before] = after;
+        self.prev[after] = before;
+        self.current = after;
+        marble
+    }
+}
+
+#[test]
+fn high_scores() -> Result<()> {
+    assert_eq!(Game { players: 9, last_marble: 25 }.high_score(), 32);
+
+    let games = [
+        ("10 players; last marble is worth 1618 points", 8317),
+        ("13 players; last marble is worth 7999 points", 146373),
+        ("17 players; last marble is worth 1104 points", 2764),
+        ("21 players; last marble is worth 6111 points", 54718),
+        ("30 players; last marble is worth 5807 points", 37305),
+    ];
+    for (game, score) in games.iter() {
+        assert_eq!(part1(&parse(game)?), *score, "{}", game);
+    }
+
+    let err = parse("10 players; last marble is worth lots points").unwrap_err().to_string();
+    assert!(err.contains("line 1, column 34"), "{}", err);
+    Ok(())
+}
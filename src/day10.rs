@@ -0,0 +1,221 @@
+//! Day 10: The Stars Align
+
+use crate::budget;
+use crate::error::Error;
+use crate::grid::{self, Bounds, Grid};
+use crate::parse;
+use crate::solution::{Answer, Result, Solution};
+use crate::visualize::{self, Frame};
+
+use std::str::FromStr;
+use std::fmt;
+
+pub fn parse(input: &str) -> Result<Map> {
+    Map::from_input(input)
+}
+
+/// The message, unless the points never come close enough to draw.
+pub fn part1(map: &Map) -> Option<String> {
+    let mut map = map.clone();
+    align(&mut map);
+    map.bounds().limit(Day10::DAY).ok()?;
+    Some(map.to_string())
+}
+
+pub fn part2(map: &Map) -> i64 {
+    align(&mut map.clone())
+}
+
+/// Steps the points forward until the area they cover stops shrinking,
+/// leaving `map` at the smallest area and returning the seconds it took.
+pub fn align(map: &mut Map) -> i64 {
+    let mut area = map.area();
+
+    let mut seconds: i64 = 0;
+    show(map, seconds);
+    while budget::step() {
+        map.increment();
+        let new_area = map.area();
+        // Points that stand still or move as one never get any closer
+        if new_area >= area {
+            map.decrement();
+            break;
+        }
+        area = new_area;
+        seconds += 1;
+        show(map, seconds);
+    }
+
+    seconds
+}
+
+/// How many cells across and down the sky is drawn in, at most.
+const SKY: (i64, i64) = (80, 30);
+
+/// Draws the sky at the start, then again every second once the points are
+/// close enough that there's something to see.
+fn show(map: &Map, seconds: i64) {
+    if visualize::watching() && (seconds == 0 || map.bounds().width() <= 10 * SKY.0) {
+        visualize::frame(|| {
+            let stars = map.0.iter().map(|p| p.position);
+            Frame::plot(format!("{} seconds", seconds), stars, SKY.0, SKY.1)
+        });
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "The Stars Align";
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Map> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<Error> {
+        parse::check_lines(input, Point::from_str)
+    }
+
+    fn part1(input: &Map) -> Answer {
+        match part1(input) {
+            Some(message) => message.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn part2(input: &Map) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Point {
+    pub position: Position,
+    pub velocity: Velocity,
+}
+
+pub type Position = grid::Point;
+pub type Velocity = grid::Point;
+
+impl Point {
+    pub fn increment(&mut self) {
+        self.position = self.position + self.velocity;
+    }
+
+    pub fn decrement(&mut self) {
+        self.position = self.position - self.velocity;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map(pub Vec<Point>);
+
+impl Map {
+    pub fn from_input(input: &str) -> Result<Map> {
+        let points = parse::lines(input, Point::from_str)?;
+
+        if points.is_empty() {
+            return Err(Error::invalid(Day10::DAY, "no points"));
+        }
+
+        Ok(Map(points))
+    }
+
+    pub fn increment(&mut self) {
+        for point in &mut self.0 {
+            point.increment()
+        }
+    }
+
+    pub fn decrement(&mut self) {
+        for point in &mut self.0 {
+            point.decrement()
+        }
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds::of(self.0.iter().map(|p| p.position)).expect("Map is empty!")
+    }
+
+    pub fn area(&self) -> i64 {
+        self.bounds().area()
+    }
+
+    /// Draws the points as `#`s on a grid just big enough to hold them.
+    pub fn render(&self) -> Grid<char> {
+        let mut sky = Grid::new(self.bounds(), ' ');
+        for point in &self.0 {
+            sky[point.position] = '#';
+        }
+
+        sky
+    }
+}
+
+const POINT: &str = "a point like `position=< 9,  1> velocity=< 0,  2>`";
+
+impl FromStr for Point {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Point> {
+        let vector = |key: &str| -> Result<(i64, i64)> {
+            let start = s.find(key)
+                .ok_or_else(|| Error::parse(Day10::DAY, 1, s, POINT))? + key.len();
+            let len = s[start..].find('>')
+                .ok_or_else(|| Error::parse(Day10::DAY, start + 1, &s[start..], POINT))?;
+            let text = &s[start..start + len];
+
+            // Real points are tens of thousands out; keeping them to `i32`
+            // leaves plenty of room to move
+            parse::pair::<i32>(text, ',')
+                .map(|(x, y)| (i64::from(x), i64::from(y)))
+                .ok_or_else(|| Error::parse(Day10::DAY, start + 1, text, "two integers like `-3, 11`"))
+        };
+
+        let (px, py) = vector("position=<")?;
+        let (vx, vy) = vector("velocity=<")?;
+
+        Ok(
+            Point {
+                position: Position {x: px, y: py},
+                velocity: Velocity {x: vx, y: vy}
+            }
+        )
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "position=<{}, {}> velocity=<{}, {}>",
+            self.position.x, self.position.y, self.velocity.x, self.velocity.y)
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn point_from_str() {
+    let s = "position=<-40271, -20082> velocity=< 4,  2>";
+    let point = Point::from_str(s).unwrap();
+    println!("{:?}", point);
+    let expected = Point {
+        position: Position {x: -40271, y: -20082},
+        velocity: Velocity {x: 4, y: 2},
+    };
+
+    assert_eq!(expected, point);
+
+    let err = Point::from_str("position=<-40271, -20082> velocity=< 4,  x>").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Day 10, column 37: expected two integers like `-3, 11`, found ` 4,  x`"
+    );
+}
\ No newline at end of file
@@ -0,0 +1,241 @@
+//! Day 11: Chronal Charge
+
+use crate::budget;
+use crate::error::Error;
+use crate::grid::{self, Bounds, Point};
+use crate::reference::Reference;
+use crate::solution::{Answer, Result, Solution, Source};
+use std::collections::{BTreeSet, BTreeMap};
+
+const MIN: i32 = i32::MIN;
+
+pub fn parse(input: &str) -> Result<Grid> {
+    let serial = input.trim().parse::<u32>()
+        .map_err(|_| Error::parse(Day11::DAY, 1, input.trim(), "a grid serial number like `7347`").at_line(1))?;
+
+    Ok(Grid::new(serial))
+}
+
+pub fn part1(grid: &Grid) -> Coord {
+    let mut power_map = BTreeMap::<Coord, i32>::new();
+
+    for coord in grid.rack.bounds().points() {
+        if let Some(vec) = grid.get3x3(coord) {
+            let power: i32 = vec.into_iter()
+                .map(|fc| fc.power_level)
+                .sum();
+
+            power_map.insert(coord, power);
+        }
+    }
+
+    let mut max = (Coord{x: 0, y: 0}, MIN);
+    for p_grid in power_map.into_iter() {
+        if p_grid.1 > max.1 {
+            max = p_grid;
+        }
+    }
+
+    max.0
+}
+
+/// The top left and size of the square with the most power, smallest and
+/// then first in reading order among equals. One size at a time, so a
+/// budget can stop it between sizes.
+pub fn part2(grid: &Grid) -> (Coord, i64) {
+    let bounds = grid.rack.bounds();
+    let totals = grid.totals();
+    let mut max = ((Coord{x: 0, y: 0}, 0), MIN);
+
+    for size in 1..=bounds.width() {
+        if !budget::step() {
+            break;
+        }
+
+        let reach = Coord { x: size - 1, y: size - 1 };
+        for coord in bounds.points().filter(|&coord| bounds.contains(coord + reach)) {
+            let power = totals[coord + reach]
+                - totals[coord + Coord { x: -1, y: size - 1 }]
+                - totals[coord + Coord { x: size - 1, y: -1 }]
+                + totals[coord + Coord { x: -1, y: -1 }];
+
+            if power > max.1 {
+                max = ((coord, size), power);
+            }
+        }
+    }
+
+    max.0
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Chronal Charge";
+    const SOURCE: Source = Source::Number;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Grid> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid) -> Answer {
+        let coord = part1(input);
+        Answer::Coords(vec![coord.x, coord.y])
+    }
+
+    fn part2(input: &Grid) -> Answer {
+        let (coord, size) = part2(input);
+        Answer::Coords(vec![coord.x, coord.y, size])
+    }
+}
+
+impl Reference for Day11 {
+    fn part1(input: &Grid) -> Answer {
+        let coord = reference::part1(input.serial);
+        Answer::Coords(vec![coord.x, coord.y])
+    }
+}
+
+/// A deliberately simple version of part 1, to check the one above against.
+pub mod reference {
+    use super::Coord;
+
+    pub fn power(x: i64, y: i64, serial: u32) -> i64 {
+        let rack = x + 10;
+        (rack * y + i64::from(serial)) * rack / 100 % 10 - 5
+    }
+
+    /// The top left of the 3x3 square with the most power, first in reading
+    /// order among equals.
+    pub fn part1(serial: u32) -> Coord {
+        let mut best = (i64::MIN, Coord { x: 0, y: 0 });
+
+        for y in 1..=298 {
+            for x in 1..=298 {
+                let mut total = 0;
+                for dy in 0..3 {
+                    for dx in 0..3 {
+                        total += power(x + dx, y + dy, serial);
+                    }
+                }
+                if total > best.0 {
+                    best = (total, Coord { x, y });
+                }
+            }
+        }
+
+        best.1
+    }
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    pub rack: grid::Grid<FuelCell>,
+    pub serial: u32,
+}
+
+impl Grid {
+    pub fn new(serial: u32) -> Grid {
+        Grid::new_with_size(serial, 300)
+    }
+
+    pub fn new_with_size(serial: u32, size: i64) -> Grid {
+        let bounds = Bounds::new(Coord { x: 1, y: 1 }, Coord { x: size, y: size });
+        let rack = grid::Grid::from_fn(bounds, |coord| FuelCell::new(coord, serial));
+
+        Grid { rack, serial }
+    }
+
+    pub fn all_squares(&self) -> BTreeSet<(Coord, i64)> {
+        let bounds = self.rack.bounds();
+        let mut all_squares = BTreeSet::new();
+
+        for coord in bounds.points() {
+            let biggest = (bounds.max.x - coord.x).min(bounds.max.y - coord.y);
+            for size in 0..=biggest {
+                all_squares.insert((coord, size));
+            }
+        }
+
+        all_squares
+    }
+
+    /// The total power of every cell above and left of each cell, itself
+    /// included, with a row and column of zeros before the rack.
+    pub fn totals(&self) -> grid::Grid<i32> {
+        let bounds = self.rack.bounds();
+        let mut totals = grid::Grid::new(Bounds::new(bounds.min + Coord { x: -1, y: -1 }, bounds.max), 0);
+
+        for coord in bounds.points() {
+            totals[coord] = self.rack[coord].power_level
+                + totals[coord + Coord { x: -1, y: 0 }]
+                + totals[coord + Coord { x: 0, y: -1 }]
+                - totals[coord + Coord { x: -1, y: -1 }];
+        }
+
+        totals
+    }
+
+    pub fn get3x3(&self, top_left: Coord) -> Option<Vec<FuelCell>> {
+        let square = Bounds::new(top_left, top_left + Coord { x: 2, y: 2 });
+
+        square.points()
+            .map(|coord| self.rack.get(coord).copied())
+            .collect()
+    }
+
+    pub fn get_square_power(&self, coord: Coord, size: i64) -> i32 {
+        let square = Bounds::new(coord, coord + Coord { x: size, y: size });
+
+        square.points()
+            .filter_map(|c| self.rack.get(c))
+            .map(|fc| fc.power_level)
+            .sum()
+    }
+}
+
+#[test]
+fn grid_test() {
+    let grid = Grid::new_with_size(100, 3);
+    let all_squares = grid.all_squares();
+    for (coord, size) in all_squares.iter() {
+        dbg!((coord, size, grid.get_square_power(*coord, *size)));
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FuelCell {
+    pub rackid: u32,
+    pub power_level: i32,
+}
+
+impl FuelCell {
+    pub fn new(coord: Coord, serial: u32) -> FuelCell {
+        let rackid = (coord.x + 10) as u32;
+        // Wide enough for any serial number
+        let power = (i64::from(rackid) * coord.y + i64::from(serial)) * i64::from(rackid);
+
+        let hundreds = (power / 100 % 10) as i32;
+        let power_level = hundreds - 5;
+
+        FuelCell { rackid, power_level }
+    }
+}
+
+pub type Coord = Point;
+
+#[test]
+fn fuelcell() {
+    let fc = FuelCell::new(Coord{x: 122, y: 79}, 57);
+    assert_eq!(fc.power_level, -5);
+
+
+    let fc = FuelCell::new(Coord{x: 217, y: 196}, 39);
+    assert_eq!(fc.power_level, 0);
+
+    let fc = FuelCell::new(Coord{x: 101, y: 153}, 71);
+    assert_eq!(fc.power_level, 4);
+}
\ No newline at end of file
@@ -0,0 +1,338 @@
+//! Day 12: Subterranean Sustainability
+
+use crate::budget;
+use crate::error::Error;
+use crate::solution::{Answer, Result, Solution};
+
+use std::str::FromStr;
+use std::fmt;
+use std::collections::BTreeMap;
+
+pub fn parse(input: &str) -> Result<(State, Rules)> {
+    let state = State::from_input(input)?;
+    let rules = Rule::rules_from_input(input)?;
+
+    Ok((state, rules))
+}
+
+pub fn part1((state, rules): &(State, Rules)) -> i32 {
+    sum_after(&mut state.clone(), rules, 20)
+}
+
+pub fn part2((state, rules): &(State, Rules)) -> i32 {
+    sum_after(&mut state.clone(), rules, 50_000_000_000)
+}
+
+pub fn sum_after(state: &mut State, rules: &Rules, generations: usize) -> i32 {
+    for gen in 0..generations {
+        if !budget::step() {
+            break;
+        }
+
+        if gen == 1000 {
+            eprintln!("Part 2: There's a pattern here...");
+        }
+
+        if gen % 1000 == 0 && gen != 0 {
+            eprintln!(
+                "{} Generations: Min: {}\tMax: {}\tSum: {}",
+                gen, state.min(), state.max(), state.sum()
+            );
+        }
+
+        state.advance(rules);
+    }
+
+    state.sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Subterranean Sustainability";
+
+    type Input = (State, Rules);
+
+    fn parse(input: &str) -> Result<(State, Rules)> {
+        parse(input)
+    }
+
+    fn part1(input: &(State, Rules)) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &(State, Rules)) -> Answer {
+        part2(input).into()
+    }
+}
+ 
+#[derive(Debug, Clone, PartialOrd, Ord, Eq, PartialEq)]
+pub struct Plant;
+
+#[derive(Debug, Clone, PartialOrd, Ord, Eq, PartialEq)]
+pub struct Pot(pub Option<Plant>);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct State {
+    pub pots: BTreeMap<i32, Pot>,
+}
+
+impl State {
+    pub fn new() -> State {
+        State { pots: BTreeMap::new() }
+    }
+
+    pub fn from_input(input: &str) -> Result<State> {
+        const PREFIX: &str = "initial state: ";
+
+        let line = input.lines().next()
+            .ok_or_else(|| Error::invalid(Day12::DAY, "no initial state"))?;
+
+        if !line.starts_with(PREFIX) {
+            return Err(Error::parse(Day12::DAY, 1, line, "`initial state: ` followed by pots").at_line(1));
+        }
+
+        State::from_str(&line[PREFIX.len()..])
+            .map_err(|e| e.offset(PREFIX.len()).at_line(1))
+    }
+
+    pub fn advance(&mut self, rules: &Rules) {
+        let mut advanced_state = State::new();
+
+        let min = self.min();
+        let max = self.max();
+
+        self.bump(&min, &max);
+
+        for (index, _pot) in self.pots.iter() {
+            let config = self.get_config(index);
+            let new_pot = config.transform(rules);
+            advanced_state.pots.insert(*index, new_pot);
+        }
+
+        self.pots = advanced_state.pots;
+    }
+
+    pub fn bump(&mut self, min: &i32, max: &i32) {
+        let first_two = self.first_two();
+        let last_two  = self.last_two();
+
+        match first_two {
+            [Some(_), Some(_)] | [Some(_), None] => {
+                self.pots.insert(min - 1, Pot(None));
+                self.pots.insert(min - 2, Pot(None));
+            },
+            [None, Some(_)] => {
+                self.pots.insert(min - 1, Pot(None));
+            },
+            [None, None] => {
+                // self.pots.remove(min);
+                // self.pots.remove(&(min + 1));
+            },
+        }
+
+        match last_two {
+            [Some(_), Some(_)] | [None, Some(_)] => {
+                self.pots.insert(max + 1, Pot(None));
+                self.pots.insert(max + 2, Pot(None));
+            },
+            [Some(_), None] => {
+                self.pots.insert(max + 1, Pot(None));
+            },
+            [None, None] => {
+                // self.pots.remove(max);
+                // self.pots.remove(&(max - 1));
+            },
+        }
+    }
+
+    pub fn first_two(&self) -> [Option<Plant>; 2] {
+        let config = self.get_config(&self.min()).0;
+
+        [config[2].0.clone(), config[3].0.clone()]
+    }
+
+    pub fn last_two(&self) -> [Option<Plant>; 2] {
+        let config = self.get_config(&self.max()).0;
+
+        [config[1].0.clone(), config[2].0.clone()]
+    }
+
+    pub fn sum(&self) -> i32 {
+        self.pots.iter()
+            .filter(|(_index, pot)| pot.0.is_some())
+            .map(|(index, _pot)| index)
+            .sum()
+    }
+
+    pub fn max(&self) -> i32 {
+        *self.pots.keys().max().expect("State is empty!")
+    }
+
+    pub fn min(&self) -> i32 {
+        *self.pots.keys().min().expect("State is empty!")
+    }
+
+    pub fn get_config(&self, index: &i32) -> Config {
+        let center = self.pots.get(index)
+            .expect("Don't call `State::get_config()` unless you know the index exists!")
+            .clone();
+
+        let left2 = self.edge_or_create(index - 2);
+        let left1 = self.edge_or_create(index - 1);
+        let right1 = self.edge_or_create(index + 1);
+        let right2 = self.edge_or_create(index + 2);
+
+        Config([left2, left1, center, right1, right2])
+    }
+
+    pub fn edge_or_create(&self, index: i32) -> Pot {
+        if let Some(pot) = self.pots.get(&index) {
+            pot.clone()
+        } else {
+            Pot(None)
+        }
+    }
+}
+
+#[derive(Debug, PartialOrd, Ord, Eq, PartialEq)]
+pub struct Config(pub [Pot; 5]);
+
+impl Config {
+    pub fn transform(&self, rules: &Rules) -> Pot {
+        if rules.is_empty() {
+            panic!("No rules! Anarchy!");
+        }
+        rules.get(self).cloned().unwrap_or(Pot(None))
+    }
+}
+
+#[derive(Debug, PartialOrd, Ord, Eq, PartialEq)]
+pub struct Rule {
+    pub config: Config,
+    pub result: Pot,
+}
+
+pub type Rules = BTreeMap<Config, Pot>;
+
+impl Rule {
+    pub fn rules_from_input(input: &str) -> Result<Rules> {
+        let mut ruleset = Rules::new();
+
+        for (index, line) in input.lines().enumerate().skip(2) {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let rule = Rule::from_str(line).map_err(|e| e.at_line(index + 1))?;
+            ruleset.insert(rule.config, rule.result);
+        }
+
+        if ruleset.is_empty() {
+            return Err(Error::invalid(Day12::DAY, "no rules"));
+        }
+
+        Ok(ruleset)
+    }
+}
+
+impl FromStr for Pot {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Pot> {
+        match s {
+            "#" => Ok(Pot(Some(Plant))),
+            "." => Ok(Pot(None)),
+            _   => Err(Error::parse(Day12::DAY, 1, s, "a pot, `#` or `.`")),
+        }
+    }
+}
+
+impl fmt::Display for Pot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}",
+            match self.0 {
+                Some(_) => "#",
+                None    => ".",
+            }
+        )
+    }
+}
+
+impl FromStr for State {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<State> {
+        let mut pots = BTreeMap::new();
+
+        for (index, c) in s.chars().enumerate() {
+            let pot = Pot::from_str(&c.to_string()).map_err(|e| e.offset(index))?;
+            pots.insert(index as i32, pot);
+        }
+
+        if pots.is_empty() {
+            return Err(Error::invalid(Day12::DAY, "no pots in the initial state"));
+        }
+
+        Ok(State { pots })
+    }
+}
+
+/// The pots from the leftmost one, as they'd appear in the input.
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for pot in self.pots.values() {
+            write!(f, "{}", pot)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Config {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Config> {
+        let pots = s.chars()
+            .enumerate()
+            .map(|(index, c)| Pot::from_str(&c.to_string()).map_err(|e| e.offset(index)))
+            .collect::<Result<Vec<Pot>>>()?;
+
+        match pots.as_slice() {
+            [a, b, c, d, e] => Ok(
+               Config([a.clone(), b.clone(), c.clone(), d.clone(), e.clone()])
+            ),
+            _ => Err(Error::parse(Day12::DAY, 1, s, "five pots like `..#.#`")),
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Rule> {
+        let split = s.split(" => ").collect::<Vec<&str>>();
+        match split.len() {
+            2 => Ok(Rule {
+                config: Config::from_str(split[0])?,
+                result: Pot::from_str(split[1]).map_err(|e| e.offset(split[0].len() + 4))?,
+            }),
+            _ => Err(Error::parse(Day12::DAY, 1, s, "a rule like `..#.# => #`"))
+        }
+    }
+}
+
+impl fmt::Display for Config {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for pot in self.0.iter() {
+            write!(f, "{}", pot)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {}", self.config, self.result)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,373 @@
+//! Day 13: Mine Cart Madness
+
+use crate::budget;
+use crate::error::Error;
+use crate::grid::{Grid, Point};
+use crate::solution::{Answer, Result, Solution};
+use crate::visualize::{self, Frame};
+use std::str::FromStr;
+use std::fmt;
+
+macro_rules! inputerr {
+    ($s:expr) => {
+        Err(Error::parse(Day13::DAY, 1, $s, "a track piece, cart or space"))
+    };
+}
+
+use Feature::*;
+use Heading::*;
+use Turn::*;
+use Orientation::*;
+use Rotation::*;
+
+pub fn parse(input: &str) -> Result<Map> {
+    Map::from_input(input)
+}
+
+/// Where the first crash happens, if there's one before the carts run out.
+pub fn part1(map: &Map) -> Option<Location> {
+    let map = &mut map.clone();
+
+    for ticks in 1.. {
+        if map.cart_count() < 2 || !budget::step() {
+            break;
+        }
+
+        let crash = map.tick();
+        visualize::frame(|| Frame::draw(format!("Tick {}", ticks), map));
+        if crash.is_some() {
+            return crash;
+        }
+    }
+
+    None
+}
+
+/// Where the last cart is once the rest have crashed, if there is one.
+pub fn part2(map: &Map) -> Option<Location> {
+    let map = &mut map.clone();
+
+    for ticks in 1.. {
+        if map.cart_count() <= 1 {
+            return map.cart_locs().first().copied();
+        }
+        if !budget::step() {
+            break;
+        }
+
+        map.tick();
+        visualize::frame(|| Frame::draw(format!("Tick {}, {} carts left", ticks, map.cart_count()), map));
+    }
+
+    None
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Mine Cart Madness";
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Map> {
+        parse(input)
+    }
+
+    fn part1(input: &Map) -> Answer {
+        match part1(input) {
+            Some(loc) => Answer::Coords(vec![loc.x, loc.y]),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn part2(input: &Map) -> Answer {
+        match part2(input) {
+            Some(loc) => Answer::Coords(vec![loc.x, loc.y]),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map(pub Grid<Position>);
+
+impl Map {
+    pub fn get(&self, loc: Location) -> &Position {
+        &self.0[loc]
+    }
+
+    pub fn tick(&mut self) -> Option<Location> {
+        let cart_locs = self.cart_locs();
+        let collisions = self.collision_check(cart_locs);
+
+        if collisions.is_empty() {
+            None
+        } else {
+            Some(collisions[0])
+        }
+    }
+
+    pub fn cart_locs(&self) -> Vec<Location> {
+        self.0.iter()
+            .filter(|(_loc, pos)| pos.cart.is_some())
+            .map(|(loc, _pos)| loc)
+            .collect()
+    }
+
+    pub fn collision_check(&mut self, cart_locs: Vec<Location>) -> Vec<Location> {
+        let mut collisions = Vec::<Location>::new();
+
+        for location in cart_locs {
+            if let Some(mut cart) = self.remove_cart(location) {
+                // A cart that comes off the tracks is out of the running
+                let new_loc = match cart.travel(location, &self.get(location).feature) {
+                    Some(new_loc) if self.0.contains(new_loc) => new_loc,
+                    _ => continue,
+                };
+
+                if self.has_cart(new_loc) {
+                    collisions.push(new_loc);
+                    self.remove_cart(new_loc);
+                } else {
+                    self.insert_cart(new_loc, cart);
+                }
+            }
+        }
+
+        collisions
+    }
+
+    pub fn remove_cart(&mut self, loc: Location) -> Option<Cart> {
+        self.0.get_mut(loc).and_then(|pos| pos.cart.take())
+    }
+
+    pub fn insert_cart(&mut self, loc: Location, cart: Cart) {
+        if let Some(position) = self.0.get_mut(loc) {
+            position.cart = Some(cart);
+        }
+    }
+
+    pub fn cart_count(&self) -> usize {
+        self.0.values()
+            .filter(|pos| pos.cart.is_some())
+            .count()
+    }
+
+    pub fn has_cart(&self, loc: Location) -> bool {
+        self.get(loc).cart.is_some()
+    }
+
+    pub fn from_input(input: &str) -> Result<Map> {
+        let grid = Grid::from_chars(input, |location, character| {
+            let feature = Feature::from_str(&character.to_string())
+                .map_err(|e| e.offset(location.x as usize).at_line(location.y as usize + 1))?;
+            let cart = Cart::new_from_char(character);
+            Ok(Position { feature, cart })
+        })?;
+        let map = Map(grid);
+
+        if map.cart_count() < 2 {
+            return Err(Error::invalid(Day13::DAY, "the map needs at least two carts"));
+        }
+
+        Ok(map)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Cart {
+    pub heading: Heading,
+    pub next_turn: Turn,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy)]
+pub enum Heading {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Turn {
+    Left,
+    Straight,
+    Right,
+}
+
+pub type Location = Point;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Position {
+    pub feature: Feature,
+    pub cart: Option<Cart>,
+}
+
+impl Default for Position {
+    fn default() -> Position {
+        Position {
+            feature: Track(Horizontal),
+            cart: None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Feature {
+    Track(Orientation),
+    Intersection, // +
+    Curve(Rotation),
+    Empty,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Orientation {
+    Horizontal, // -
+    Vertical,   // |
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Rotation {
+    Clockwise,        // `/`
+    CounterClockwise, // `\`
+}
+
+impl Cart {
+    pub fn new_from_char(c: char) -> Option<Cart> {
+        let direction = match c {
+            '>' => Some(East),
+            '<' => Some(West),
+            '^' => Some(North),
+            'v' => Some(South),
+            _ => None,
+        };
+
+        direction.map(|heading| Cart {
+            heading,
+            next_turn: Left
+        })
+    }
+
+    /// Follows the track at `loc` and returns where the cart ends up, or
+    /// None if there's no track there.
+    pub fn travel(&mut self, loc: Location, feature: &Feature) -> Option<Location> {
+        match feature {
+            Track(_) => (),
+            Intersection => self.turn(),
+            Curve(dir) => self.curve(dir),
+            Empty => return None,
+        }
+
+        Some(loc + self.heading.offset())
+    }
+
+    pub fn turn(&mut self) {
+        self.heading = self.heading.turn(&self.next_turn);
+        self.next_turn = self.next_turn.next();
+    }
+
+    pub fn curve(&mut self, dir: &Rotation) {
+        self.heading = self.heading.curve(dir);
+    }
+}
+
+impl Heading {
+    pub fn offset(self) -> Point {
+        match self {
+            North => Point::new(0, -1),
+            South => Point::new(0, 1),
+            East  => Point::new(1, 0),
+            West  => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn(&self, turn: &Turn) -> Heading {
+        match (self, turn) {
+            (_, Straight) => *self,
+            (North, Right) | (South, Left) => East,
+            (East,  Right) | (West,  Left) => South,
+            (South, Right) | (North, Left) => West,
+            (West,  Right) | (East,  Left) => North,
+        }
+    }
+
+    pub fn curve(&self, rotation: &Rotation) -> Heading {
+        match (self, rotation) {
+            (North, Clockwise) | (South, CounterClockwise) => East,
+            (North, CounterClockwise) | (South, Clockwise) => West,
+            (East, Clockwise) | (West, CounterClockwise)   => North,
+            (East, CounterClockwise) | (West, Clockwise)   => South,
+        }
+    }
+}
+
+impl Turn {
+    pub fn next(&self) -> Turn {
+        match self {
+            Turn::Left => Turn::Straight,
+            Turn::Straight => Turn::Right,
+            Turn::Right => Turn::Left,
+        }
+    }
+}
+
+impl FromStr for Feature {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Feature> {
+        match s {
+            "-" | ">" | "<" => Ok(Track(Horizontal)),
+            "|" | "^" | "v" => Ok(Track(Vertical)),
+            "+"  => Ok(Intersection),
+            "/"  => Ok(Curve(Clockwise)),
+            "\\" => Ok(Curve(CounterClockwise)),
+            " "  => Ok(Empty),
+            _ => inputerr!(s),
+        }
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}",
+            match self {
+                Track(Horizontal)       => '-',
+                Track(Vertical)         => '|',
+                Intersection            => '+',
+                Curve(Clockwise)        => '/',
+                Curve(CounterClockwise) => '\\',
+                Empty                   => ' ',
+            }
+        )
+    }
+}
+
+impl fmt::Display for Cart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}",
+            match self.heading {
+                North => '^',
+                South => 'v',
+                East  => '>',
+                West  => '<',
+            }
+        )
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}",
+            match &self.cart {
+                Some(cart) => cart.to_string(),
+                None => self.feature.to_string(),
+            }
+        )
+    }
+}
\ No newline at end of file
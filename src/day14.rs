@@ -0,0 +1,135 @@
+//! Day 14: Chocolate Charts
+
+use crate::budget;
+use crate::error::Error;
+use crate::solution::{Answer, Result, Solution, Source};
+
+/// The most scores the board is allowed to grow to, a byte each. Real
+/// inputs are found in a few tens of millions.
+pub const MOST_RECIPES: usize = 100_000_000;
+
+/// The puzzle input, kept as digits: part 1 reads it as a number, part 2 as a
+/// sequence of scores, where leading zeros count.
+pub fn parse(input: &str) -> Result<Vec<u8>> {
+    let input = input.trim();
+    if input.is_empty() || !input.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::parse(Day14::DAY, 1, input, "a number of recipes like `894501`").at_line(1));
+    }
+
+    Ok(input.bytes().map(|b| b - b'0').collect())
+}
+
+/// The ten scores after the first `recipes` recipes, unless that's more
+/// than [`MOST_RECIPES`].
+pub fn part1(digits: &[u8]) -> Option<String> {
+    let recipes = digits.iter()
+        .try_fold(0_usize, |n, &d| n.checked_mul(10)?.checked_add(d as usize))
+        .filter(|&recipes| recipes <= MOST_RECIPES - 10)?;
+    let mut board = Scoreboard::new();
+
+    while board.scores.len() < recipes + 10 && budget::step() {
+        board.step();
+    }
+
+    board.scores.get(recipes..recipes + 10).map(|scores| {
+        scores.iter()
+            .map(|score| char::from(b'0' + score))
+            .collect()
+    })
+}
+
+/// How many recipes come before `digits` first shows up on the scoreboard,
+/// if it does within [`MOST_RECIPES`].
+pub fn part2(digits: &[u8]) -> Option<usize> {
+    let mut board = Scoreboard::new();
+    let mut checked = 0;
+
+    while board.scores.len() < MOST_RECIPES && budget::step() {
+        board.step();
+        // A step adds at most two scores, so the match can end on either
+        while checked + digits.len() <= board.scores.len() {
+            if board.scores[checked..checked + digits.len()] == *digits {
+                return Some(checked);
+            }
+            checked += 1;
+        }
+    }
+
+    None
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Chocolate Charts";
+    const SOURCE: Source = Source::Number;
+
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Vec<u8>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<u8>) -> Answer {
+        match part1(input) {
+            Some(scores) => scores.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn part2(input: &Vec<u8>) -> Answer {
+        match part2(input) {
+            Some(recipes) => recipes.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scoreboard {
+    pub scores: Vec<u8>,
+    pub elves: [usize; 2],
+}
+
+impl Scoreboard {
+    pub fn new() -> Scoreboard {
+        Scoreboard {
+            scores: vec![3, 7],
+            elves: [0, 1],
+        }
+    }
+
+    /// Combines the elves' current recipes, then moves each elf on.
+    pub fn step(&mut self) {
+        let sum = self.scores[self.elves[0]] + self.scores[self.elves[1]];
+        if sum >= 10 {
+            self.scores.push(sum / 10);
+        }
+        self.scores.push(sum % 10);
+
+        for elf in self.elves.iter_mut() {
+            *elf = (*elf + 1 + self.scores[*elf] as usize) % self.scores.len();
+        }
+    }
+}
+
+impl Default for Scoreboard {
+    fn default() -> Scoreboard {
+        Scoreboard::new()
+    }
+}
+
+#[test]
+fn scoreboard() -> Result<()> {
+    assert_eq!(part1(&parse("9")?).as_deref(), Some("5158916779"));
+    assert_eq!(part1(&parse("2018")?).as_deref(), Some("5941429882"));
+    assert_eq!(part1(&parse("99999999999")?), None);
+
+    assert_eq!(part2(&parse("51589")?), Some(9));
+    assert_eq!(part2(&parse("01245")?), Some(5));
+    assert_eq!(part2(&parse("59414")?), Some(2018));
+
+    assert!(parse("12a").is_err());
+    Ok(())
+}
@@ -0,0 +1,365 @@
+//! Day 15: Beverage Bandits
+
+use crate::budget;
+use crate::error::Error;
+use crate::grid::{Grid, Point};
+use crate::path;
+use crate::solution::{Answer, Result, Solution};
+use crate::visualize::{self, Frame};
+use std::str::FromStr;
+use std::fmt;
+
+use CreatureType::{Elf, Goblin};
+use Feature::{Unit, Wall};
+
+const HIT_POINTS: usize = 200;
+const ATTACK_POWER: usize = 3;
+
+pub fn parse(input: &str) -> Result<Map> {
+    Map::from_str(input)
+}
+
+pub fn part1(map: &Map) -> Outcome {
+    map.clone().fight()
+}
+
+/// The outcome with the weakest elves that win without losing anyone.
+pub fn part2(map: &Map) -> Outcome {
+    let elves = map.count(Elf);
+
+    for power in ATTACK_POWER + 1.. {
+        let mut map = Map { elf_power: power, ..map.clone() };
+        let outcome = map.fight();
+
+        if (outcome.winner == Elf && map.count(Elf) == elves) || !budget::step() {
+            return outcome;
+        }
+    }
+
+    unreachable!("elves that one-shot every goblin can't lose")
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Beverage Bandits";
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Map> {
+        parse(input)
+    }
+
+    fn part1(input: &Map) -> Answer {
+        part1(input).score().into()
+    }
+
+    fn part2(input: &Map) -> Answer {
+        part2(input).score().into()
+    }
+}
+
+/// How a fight ended: who was left standing, after how many full rounds, and
+/// with how many hit points between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub winner: CreatureType,
+    pub rounds: usize,
+    pub hit_points: usize,
+}
+
+impl Outcome {
+    pub fn score(&self) -> usize {
+        self.rounds * self.hit_points
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    pub layout: Grid<Option<Feature>>,
+    pub elf_power: usize,
+}
+
+impl Map {
+    /// Runs rounds until one side is wiped out.
+    pub fn fight(&mut self) -> Outcome {
+        let mut rounds = 0;
+        while budget::step() && self.round() {
+            rounds += 1;
+            visualize::frame(|| {
+                let caption = format!("Round {}, elves attacking with {}: {} elves, {} goblins",
+                    rounds, self.elf_power, self.count(Elf), self.count(Goblin));
+                Frame::draw(caption, self)
+            });
+        }
+
+        let units = self.units();
+        Outcome {
+            winner: units.first().map_or(Elf, |(_, creature)| creature.team),
+            rounds,
+            hit_points: units.iter().map(|(_, creature)| creature.hit_points).sum(),
+        }
+    }
+
+    /// Every unit takes a turn, in reading order. Returns false if a unit
+    /// found no enemies left, so the round didn't finish.
+    pub fn round(&mut self) -> bool {
+        for (point, creature) in self.units() {
+            // Units killed earlier in the round don't get a turn
+            if self.creature(point).map(|c| c.id) != Some(creature.id) {
+                continue;
+            }
+
+            if !self.units().iter().any(|(_, other)| creature.is_enemy(other)) {
+                return false;
+            }
+
+            let point = self.advance(point);
+            self.attack(point);
+        }
+
+        true
+    }
+
+    /// Moves the unit at `point` a step towards the nearest enemy, unless it
+    /// is already next to one, and returns where it ends up.
+    pub fn advance(&mut self, point: Point) -> Point {
+        if self.adjacent_enemy(point, point).is_some() {
+            return point;
+        }
+
+        let search = path::bfs(&path::walkable(&self.layout, Option::is_none), point);
+        let step = search.nearest(|p| p != point && self.adjacent_enemy(point, p).is_some())
+            .and_then(|(square, _)| search.first_step(square));
+
+        match step {
+            Some(step) => {
+                self.layout[step] = self.layout[point].take();
+                step
+            },
+            None => point,
+        }
+    }
+
+    /// Hits the weakest enemy next to the unit at `point`, if there is one.
+    pub fn attack(&mut self, point: Point) {
+        let power = match self.creature(point) {
+            Some(Creature { team: Elf, .. }) => self.elf_power,
+            Some(Creature { team: Goblin, .. }) => ATTACK_POWER,
+            None => return,
+        };
+
+        if let Some(target) = self.adjacent_enemy(point, point) {
+            if let Some(Unit(enemy)) = &mut self.layout[target] {
+                if enemy.attacked(power) {
+                    self.layout[target] = None;
+                }
+            }
+        }
+    }
+
+    /// The weakest enemy of the unit at `unit` next to `square`, first in
+    /// reading order among equals.
+    fn adjacent_enemy(&self, unit: Point, square: Point) -> Option<Point> {
+        let creature = self.creature(unit)?;
+
+        self.layout.neighbours4(square)
+            .filter_map(|n| self.creature(n).map(|other| (n, other)))
+            .filter(|(_, other)| creature.is_enemy(other))
+            .min_by_key(|(_, other)| other.hit_points)
+            .map(|(n, _)| n)
+    }
+
+    pub fn creature(&self, point: Point) -> Option<Creature> {
+        match self.layout.get(point) {
+            Some(Some(Unit(creature))) => Some(*creature),
+            _ => None,
+        }
+    }
+
+    /// The units still alive, in reading order.
+    pub fn units(&self) -> Vec<(Point, Creature)> {
+        self.layout.iter()
+            .filter_map(|(p, f)| match f {
+                Some(Unit(creature)) => Some((p, *creature)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn count(&self, team: CreatureType) -> usize {
+        self.units().iter().filter(|(_, c)| c.team == team).count()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum CreatureType {
+    Elf,
+    Goblin,
+}
+
+impl CreatureType {
+    pub fn enemy(&self) -> CreatureType {
+        match self {
+            Elf => Goblin,
+            Goblin => Elf,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Area<'a> {
+    pub center: &'a Point,  // The middle point
+    pub around: [Point; 8], // The surrounding 8 points
+}
+
+impl<'a> From<&'a Point> for Area<'a> {
+    fn from(center: &'a Point) -> Area<'a> {
+        let mut around = [*center; 8];
+        for (point, neighbour) in around.iter_mut().zip(center.neighbours8()) {
+            *point = neighbour;
+        }
+
+        Area { center, around }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Creature {
+    pub team: CreatureType,
+    pub hit_points: usize,
+    /// Tells units apart as they move around
+    pub id: usize,
+}
+
+impl Creature {
+    /// Takes a hit, returning true if it was fatal.
+    pub fn attacked(&mut self, power: usize) -> bool {
+        self.hit_points = self.hit_points.saturating_sub(power);
+        self.hit_points == 0
+    }
+
+    pub fn is_enemy(&self, other: &Creature) -> bool {
+        self.team.enemy() == other.team
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Feature {
+    Unit(Creature),
+    Wall,
+}
+
+impl Feature {
+    pub fn is_unit(&self) -> bool {
+        matches!(self, Unit(_))
+    }
+
+    pub fn unwrap_unit(self) -> Creature {
+        if let Unit(creature) = self {
+            creature
+        } else {
+            panic!("Attempted to unwrap a Wall!");
+        }
+    }
+}
+
+#[test]
+fn area_from_point() {
+    let point = Point { x: 1, y: 1 };
+    let area_expected = Area {
+        center: &point,
+        around: [
+            Point { x: 0, y: 0 }, Point { x: 1, y: 0 }, Point { x: 2, y: 0 },
+            Point { x: 0, y: 1 },                       Point { x: 2, y: 1 },
+            Point { x: 0, y: 2 }, Point { x: 1, y: 2 }, Point { x: 2, y: 2 },
+        ],
+    };
+    let area_from = Area::from(&point);
+
+    assert_eq!(area_expected, area_from);
+
+    let _ = Area::from(&Point { x: 0, y: 0} );
+}
+
+#[test]
+fn combat() -> Result<()> {
+    let example = "\
+#######
+#G..#E#
+#E#E.E#
+#G.##.#
+#...#E#
+#...E.#
+#######";
+    let outcome = part1(&parse(example)?);
+    assert_eq!(outcome, Outcome { winner: Elf, rounds: 37, hit_points: 982 });
+
+    let example = "\
+#######
+#E.G#.#
+#.#G..#
+#G.#.G#
+#G..#.#
+#...E.#
+#######";
+    assert_eq!(part1(&parse(example)?).score(), 27755);
+    assert_eq!(part2(&parse(example)?).score(), 3478);
+    Ok(())
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Map> {
+        let mut id = 0;
+        let mut unit = |team| {
+            id += 1;
+            Ok(Some(Unit(Creature { team, hit_points: HIT_POINTS, id })))
+        };
+
+        let layout = Grid::from_chars(s.trim_end(), |point, c| match c {
+            'E' => unit(Elf),
+            'G' => unit(Goblin),
+            '#' => Ok(Some(Wall)),
+            '.' => Ok(None),
+            _   => Err(
+                Error::parse(Day15::DAY, point.x as usize + 1, &c.to_string(), "`#`, `.`, `E` or `G`")
+                    .at_line(point.y as usize + 1)
+            ),
+        })?;
+
+        Ok(Map { layout, elf_power: ATTACK_POWER })
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}",
+            if let Unit(creature) = self {
+                match creature.team {
+                    Elf => 'E',
+                    Goblin => 'G',
+                }
+            } else {
+                '#'
+        })
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.layout.rows() {
+            for feature in row {
+                match feature {
+                    Some(feat) => write!(f, "{}", feat)?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
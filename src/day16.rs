@@ -0,0 +1,185 @@
+//! Day 16: Chronal Classification
+
+use crate::device::Opcode;
+use crate::error::Error;
+use crate::parse;
+use crate::solution::{Answer, Result, Solution};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+type Registers = [usize; 4];
+
+pub fn parse(input: &str) -> Result<Manual> {
+    let mut manual = Manual::default();
+    let mut lines = input.lines().enumerate();
+
+    while let Some((index, line)) = lines.next() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if line.starts_with("Before:") {
+            let before = registers(line, "Before:").map_err(|e| e.at_line(index + 1))?;
+
+            let (index, line) = lines.next().unwrap_or((index + 1, ""));
+            let instruction = numbers(line).map_err(|e| e.at_line(index + 1))?;
+
+            let (index, line) = lines.next().unwrap_or((index + 1, ""));
+            let after = registers(line, "After:").map_err(|e| e.at_line(index + 1))?;
+
+            manual.samples.push(Sample { before, instruction, after });
+        } else {
+            manual.program.push(numbers(line).map_err(|e| e.at_line(index + 1))?);
+        }
+    }
+
+    Ok(manual)
+}
+
+/// Parses a line like `Before: [3, 2, 1, 1]`.
+fn registers(line: &str, label: &'static str) -> Result<Registers> {
+    const REGISTERS: &str = "registers like `[3, 2, 1, 1]`";
+
+    let rest = line.strip_prefix(label)
+        .ok_or_else(|| Error::parse(Day16::DAY, 1, line, label))?;
+    let offset = label.len() + rest.len() - rest.trim_start().len();
+    let list = rest.trim();
+
+    let values = list.strip_prefix('[')
+        .and_then(|list| list.strip_suffix(']'))
+        .map(|list| list.split(',').map(|n| n.trim().parse::<usize>()).collect::<Vec<_>>())
+        .filter(|values| values.len() == 4 && values.iter().all(|n| n.is_ok()))
+        .ok_or_else(|| Error::parse(Day16::DAY, 1, list, REGISTERS).offset(offset))?;
+
+    let mut registers = [0; 4];
+    for (register, value) in registers.iter_mut().zip(values) {
+        *register = value.unwrap_or_default();
+    }
+
+    Ok(registers)
+}
+
+/// Parses an instruction like `9 2 1 2`.
+fn numbers(line: &str) -> Result<[usize; 4]> {
+    const INSTRUCTION: &str = "an instruction like `9 2 1 2`";
+
+    let fields = parse::fields(line);
+    if fields.len() != 4 {
+        return Err(Error::parse(Day16::DAY, 1, line.trim(), INSTRUCTION));
+    }
+
+    let mut numbers = [0; 4];
+    for (number, (column, text)) in numbers.iter_mut().zip(fields) {
+        *number = text.parse()
+            .map_err(|_| Error::parse(Day16::DAY, column, text, "a whole number"))?;
+    }
+
+    Ok(numbers)
+}
+
+pub fn part1(manual: &Manual) -> usize {
+    manual.samples.iter()
+        .filter(|sample| sample.candidates().count() >= 3)
+        .count()
+}
+
+/// Register 0 after running the program, once the samples have pinned down
+/// every opcode's number.
+pub fn part2(manual: &Manual) -> Option<usize> {
+    let opcodes = manual.opcodes()?;
+    let mut registers = [0; 4];
+
+    for &[number, a, b, c] in &manual.program {
+        opcodes.get(&number)?.execute(a, b, c, &mut registers)?;
+    }
+
+    Some(registers[0])
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Chronal Classification";
+
+    type Input = Manual;
+
+    fn parse(input: &str) -> Result<Manual> {
+        parse(input)
+    }
+
+    fn part1(input: &Manual) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Manual) -> Answer {
+        match part2(input) {
+            Some(r0) => r0.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manual {
+    pub samples: Vec<Sample>,
+    pub program: Vec<[usize; 4]>,
+}
+
+impl Manual {
+    /// Works out which number is which opcode: narrow each number down to the
+    /// opcodes all its samples agree with, then repeatedly settle the numbers
+    /// left with only one.
+    pub fn opcodes(&self) -> Option<BTreeMap<usize, Opcode>> {
+        let mut possible = BTreeMap::<usize, BTreeSet<Opcode>>::new();
+        for sample in &self.samples {
+            let candidates = sample.candidates().collect::<BTreeSet<Opcode>>();
+            possible.entry(sample.instruction[0])
+                .and_modify(|ops| ops.retain(|op| candidates.contains(op)))
+                .or_insert(candidates);
+        }
+
+        let mut known = BTreeMap::new();
+        while let Some((&number, ops)) = possible.iter().find(|(_, ops)| ops.len() == 1) {
+            let op = *ops.iter().next()?;
+            known.insert(number, op);
+            possible.remove(&number);
+            for ops in possible.values_mut() {
+                ops.remove(&op);
+            }
+        }
+
+        if possible.is_empty() { Some(known) } else { None }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    pub before: Registers,
+    pub instruction: [usize; 4],
+    pub after: Registers,
+}
+
+impl Sample {
+    /// The opcodes that would turn `before` into `after`.
+    pub fn candidates(&self) -> impl Iterator<Item = Opcode> + '_ {
+        let [_, a, b, c] = self.instruction;
+
+        Opcode::ALL.iter().copied().filter(move |op| {
+            let mut registers = self.before;
+            op.execute(a, b, c, &mut registers).is_some() && registers == self.after
+        })
+    }
+}
+
+#[test]
+fn samples() -> Result<()> {
+    let manual = parse("Before: [3, 2, 1, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]\n\n\n\n9 0 0 1\n")?;
+    assert_eq!(manual.samples[0].candidates().collect::<Vec<_>>(), vec![Opcode::Addi, Opcode::Mulr, Opcode::Seti]);
+    assert_eq!(part1(&manual), 1);
+    assert_eq!(manual.program, vec![[9, 0, 0, 1]]);
+
+    let err = parse("Before: [3, 2, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]\n").unwrap_err().to_string();
+    assert!(err.contains("line 1, column 9"), "{}", err);
+    Ok(())
+}
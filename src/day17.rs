@@ -0,0 +1,244 @@
+//! Day 17: Reservoir Research
+
+use crate::error::Error;
+use crate::grid::{Bounds, Grid, Point};
+use crate::parse;
+use crate::solution::{Answer, Result, Solution};
+
+use std::fmt;
+
+/// Where the water comes from.
+pub const SPRING: Point = Point { x: 500, y: 0 };
+
+pub fn parse(input: &str) -> Result<Vec<Vein>> {
+    let veins = parse::lines(input, vein)?;
+    if veins.is_empty() {
+        return Err(Error::invalid(Day17::DAY, "no clay veins"));
+    }
+    if let Some(vein) = veins.iter().find(|v| v.min.y <= SPRING.y) {
+        return Err(Error::invalid(Day17::DAY, format!("the clay at {} isn't below the spring", vein.min)));
+    }
+    Ground::bounds(&veins).0.limit(Day17::DAY)?;
+
+    Ok(veins)
+}
+
+/// Tiles the water reaches, flowing or settled.
+pub fn part1(veins: &[Vein]) -> usize {
+    Ground::flood(veins).count(|tile| matches!(tile, Tile::Flowing | Tile::Settled))
+}
+
+/// Tiles where the water settles and stays once the spring runs dry.
+pub fn part2(veins: &[Vein]) -> usize {
+    Ground::flood(veins).count(|tile| tile == Tile::Settled)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Reservoir Research";
+
+    type Input = Vec<Vein>;
+
+    fn parse(input: &str) -> Result<Vec<Vein>> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<Error> {
+        parse::check_lines(input, vein)
+    }
+
+    fn part1(input: &Vec<Vein>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Vec<Vein>) -> Answer {
+        part2(input).into()
+    }
+}
+
+/// A line of clay, one square wide.
+pub type Vein = Bounds;
+
+/// Parses `x=495, y=2..7` or `y=7, x=495..501`.
+pub fn vein(s: &str) -> Result<Vein> {
+    const VEIN: &str = "a vein like `x=495, y=2..7`";
+
+    let (first, second) = s.split_once(", ")
+        .ok_or_else(|| Error::parse(Day17::DAY, 1, s.trim(), VEIN))?;
+    let offset = first.len() + 2;
+
+    let (axis, at) = first.split_once('=')
+        .and_then(|(axis, at)| Some((axis, at.parse::<i64>().ok()?)))
+        .ok_or_else(|| Error::parse(Day17::DAY, 1, first, VEIN))?;
+
+    let (other, from, to) = second.trim_end().split_once('=')
+        .and_then(|(other, range)| {
+            let (from, to) = range.split_once("..")?;
+            Some((other, from.parse::<i64>().ok()?, to.parse::<i64>().ok()?))
+        })
+        .filter(|&(_, from, to)| from <= to)
+        .ok_or_else(|| Error::parse(Day17::DAY, 1, second.trim_end(), "a range like `y=2..7`").offset(offset))?;
+
+    match (axis, other) {
+        ("x", "y") => Ok(Bounds::new(Point::new(at, from), Point::new(at, to))),
+        ("y", "x") => Ok(Bounds::new(Point::new(from, at), Point::new(to, at))),
+        _ => Err(Error::parse(Day17::DAY, 1, s.trim(), VEIN)),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Sand,
+    Clay,
+    /// Water passing through
+    Flowing,
+    /// Water held in place by clay
+    Settled,
+}
+
+impl Tile {
+    /// Whether water can rest on top of this tile.
+    fn holds_water(self) -> bool {
+        matches!(self, Tile::Clay | Tile::Settled)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Ground {
+    pub tiles: Grid<Tile>,
+    /// The rows that count: from the shallowest clay to the deepest.
+    pub depth: (i64, i64),
+}
+
+impl Ground {
+    pub fn new(veins: &[Vein]) -> Ground {
+        let (bounds, clay) = Ground::bounds(veins);
+
+        let mut tiles = Grid::new(bounds, Tile::Sand);
+        for vein in veins {
+            for point in vein.points() {
+                tiles[point] = Tile::Clay;
+            }
+        }
+
+        Ground { tiles, depth: (clay.min.y, clay.max.y) }
+    }
+
+    /// The ground the water can reach, from the spring down to the deepest
+    /// clay, and the bounds of the clay itself.
+    pub fn bounds(veins: &[Vein]) -> (Bounds, Bounds) {
+        let clay = Bounds::of(veins.iter().flat_map(|v| vec![v.min, v.max]))
+            .unwrap_or_else(|| Bounds::new(SPRING, SPRING));
+        // Water can spill one column past the outermost clay
+        let bounds = Bounds::new(
+            Point::new(clay.min.x.min(SPRING.x).saturating_sub(1), SPRING.y),
+            Point::new(clay.max.x.max(SPRING.x).saturating_add(1), clay.max.y),
+        );
+
+        (bounds, clay)
+    }
+
+    /// Lets the spring run until the water stops changing.
+    pub fn flood(veins: &[Vein]) -> Ground {
+        let mut ground = Ground::new(veins);
+        ground.fall(SPRING + Point::new(0, 1));
+        ground
+    }
+
+    pub fn count<F: Fn(Tile) -> bool>(&self, f: F) -> usize {
+        let (top, bottom) = self.depth;
+        self.tiles.iter()
+            .filter(|(p, &tile)| p.y >= top && p.y <= bottom && f(tile))
+            .count()
+    }
+
+    /// Water falls from `point` until it lands on something, then spreads
+    /// sideways. If both sides end at clay the row settles.
+    fn fall(&mut self, point: Point) {
+        if self.tiles.get(point) != Some(&Tile::Sand) {
+            return;
+        }
+        self.tiles[point] = Tile::Flowing;
+
+        let below = point + Point::new(0, 1);
+        if !self.tiles.contains(below) {
+            return;
+        }
+        if self.tiles[below] == Tile::Sand {
+            self.fall(below);
+        }
+        if !self.tiles[below].holds_water() {
+            return;
+        }
+
+        let left = self.spread(point, -1);
+        let right = self.spread(point, 1);
+
+        if let (Some(left), Some(right)) = (left, right) {
+            for x in left..=right {
+                self.tiles[Point::new(x, point.y)] = Tile::Settled;
+            }
+        }
+    }
+
+    /// Spreads water from `point` in direction `dx`, returning the last
+    /// column before clay, or `None` if it spilled over an edge.
+    fn spread(&mut self, point: Point, dx: i64) -> Option<i64> {
+        let mut x = point.x;
+
+        loop {
+            let next = Point::new(x + dx, point.y);
+            if self.tiles[next] == Tile::Clay {
+                return Some(x);
+            }
+
+            if self.tiles[next] == Tile::Sand {
+                self.tiles[next] = Tile::Flowing;
+            }
+            x = next.x;
+
+            let below = next + Point::new(0, 1);
+            // Over the edge, unless the water fills a basin down there
+            self.fall(below);
+            if !self.tiles.get(below).is_some_and(|tile| tile.holds_water()) {
+                return None;
+            }
+        }
+    }
+}
+
+impl fmt::Display for Ground {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let chars = Grid::from_fn(self.tiles.bounds(), |p| match self.tiles[p] {
+            _ if p == SPRING => '+',
+            Tile::Sand => '.',
+            Tile::Clay => '#',
+            Tile::Flowing => '|',
+            Tile::Settled => '~',
+        });
+
+        write!(f, "{}", chars)
+    }
+}
+
+#[test]
+fn reservoir() -> Result<()> {
+    let veins = parse("\
+x=495, y=2..7
+y=7, x=495..501
+x=501, y=3..7
+x=498, y=2..4
+x=506, y=1..2
+x=498, y=10..13
+x=504, y=10..13
+y=13, x=498..504
+")?;
+    assert_eq!(part1(&veins), 57);
+    assert_eq!(part2(&veins), 29);
+
+    let err = parse("x=495, y=7..2").unwrap_err().to_string();
+    assert!(err.contains("column 8"), "{}", err);
+    Ok(())
+}
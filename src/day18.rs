@@ -0,0 +1,148 @@
+//! Day 18: Settlers of The North Pole
+
+use crate::error::Error;
+use crate::grid::Grid;
+use crate::solution::{Answer, Result, Solution};
+
+use std::collections::HashMap;
+use std::fmt;
+
+pub fn parse(input: &str) -> Result<Area> {
+    let acres = Grid::from_chars(input.trim_end(), |point, c| match c {
+        '.' => Ok(Acre::Open),
+        '|' => Ok(Acre::Trees),
+        '#' => Ok(Acre::Lumberyard),
+        _ => Err(
+            Error::parse(Day18::DAY, point.x as usize + 1, &c.to_string(), "`.`, `|` or `#`")
+                .at_line(point.y as usize + 1)
+        ),
+    })?;
+
+    if acres.bounds().area() == 0 {
+        return Err(Error::invalid(Day18::DAY, "no acres"));
+    }
+
+    Ok(Area { acres })
+}
+
+pub fn part1(area: &Area) -> usize {
+    area.after(10).resource_value()
+}
+
+pub fn part2(area: &Area) -> usize {
+    area.after(1_000_000_000).resource_value()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Settlers of The North Pole";
+
+    type Input = Area;
+
+    fn parse(input: &str) -> Result<Area> {
+        parse(input)
+    }
+
+    fn part1(input: &Area) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Area) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Acre {
+    Open,
+    Trees,
+    Lumberyard,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Area {
+    pub acres: Grid<Acre>,
+}
+
+impl Area {
+    /// One minute of growth and building.
+    pub fn tick(&self) -> Area {
+        let acres = Grid::from_fn(self.acres.bounds(), |point| {
+            let count = |acre| self.acres.neighbours8(point)
+                .filter(|&n| self.acres[n] == acre)
+                .count();
+
+            match self.acres[point] {
+                Acre::Open if count(Acre::Trees) >= 3 => Acre::Trees,
+                Acre::Trees if count(Acre::Lumberyard) >= 3 => Acre::Lumberyard,
+                Acre::Lumberyard if count(Acre::Lumberyard) == 0 || count(Acre::Trees) == 0 => Acre::Open,
+                acre => acre,
+            }
+        });
+
+        Area { acres }
+    }
+
+    /// The area after `minutes`. The area soon settles into a loop, so once
+    /// a state comes round again the rest is skipped.
+    pub fn after(&self, minutes: usize) -> Area {
+        let mut seen = HashMap::new();
+        let mut history = vec![self.clone()];
+
+        for minute in 1..=minutes {
+            let next = history[minute - 1].tick();
+
+            if let Some(&start) = seen.get(&next) {
+                let period = minute - start;
+                return history.swap_remove(start + (minutes - start) % period);
+            }
+
+            seen.insert(next.clone(), minute);
+            history.push(next);
+        }
+
+        history.swap_remove(minutes)
+    }
+
+    pub fn resource_value(&self) -> usize {
+        let count = |acre| self.acres.values().filter(|&&a| a == acre).count();
+        count(Acre::Trees) * count(Acre::Lumberyard)
+    }
+}
+
+impl fmt::Display for Acre {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", match self {
+            Acre::Open => '.',
+            Acre::Trees => '|',
+            Acre::Lumberyard => '#',
+        })
+    }
+}
+
+impl fmt::Display for Area {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.acres)
+    }
+}
+
+#[test]
+fn lumber() -> Result<()> {
+    let area = parse("\
+.#.#...|#.
+.....#|##|
+.|..|...#.
+..|#.....#
+#.#|||#|#|
+...#.||...
+.|....|...
+||...#|.#|
+|.||||..|.
+...#.|..|.
+")?;
+    assert_eq!(part1(&area), 1147);
+    assert_eq!(area.after(3), area.tick().tick().tick());
+    Ok(())
+}
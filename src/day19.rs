@@ -0,0 +1,69 @@
+//! Day 19: Go With The Flow
+
+use crate::device::{Cpu, Program};
+use crate::solution::{Answer, Result, Solution};
+
+pub fn parse(input: &str) -> Result<Program> {
+    Program::parse(Day19::DAY, input)
+}
+
+pub fn part1(program: &Program) -> usize {
+    let mut cpu = Cpu::new([0; 6]);
+    cpu.run(program);
+    cpu.registers[0]
+}
+
+/// Starting with register 0 set to 1 makes the program's target number huge,
+/// and the program sums its divisors one pair at a time. So let it build the
+/// number, which is done once it jumps back to instruction 1, and sum the
+/// divisors here.
+pub fn part2(program: &Program) -> usize {
+    let mut cpu = Cpu::new([1, 0, 0, 0, 0, 0]);
+    cpu.step(program);
+
+    if !cpu.run_until(program, |cpu| cpu.ip == 1) {
+        return cpu.registers[0];
+    }
+
+    let target = cpu.registers.iter().copied().max().unwrap_or(0);
+    divisor_sum(target)
+}
+
+pub fn divisor_sum(n: usize) -> usize {
+    (1..)
+        .take_while(|d| d * d <= n)
+        .filter(|d| n.is_multiple_of(*d))
+        .map(|d| if d * d == n { d } else { d + n / d })
+        .sum()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Go With The Flow";
+
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Program> {
+        parse(input)
+    }
+
+    fn part1(input: &Program) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Program) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[test]
+fn flow() -> Result<()> {
+    let program = parse("#ip 0\nseti 5 0 1\nseti 6 0 2\naddi 0 1 0\naddr 1 2 3\nsetr 1 0 0\nseti 8 0 4\nseti 9 0 5\n")?;
+    assert_eq!(part1(&program), 6);
+
+    assert_eq!(divisor_sum(836), 1680);
+    assert_eq!(divisor_sum(1), 1);
+    Ok(())
+}
@@ -0,0 +1,143 @@
+//! Day 20: A Regular Map
+
+use crate::error::Error;
+use crate::grid::Point;
+use crate::path::{self, Search};
+use crate::solution::{Answer, Result, Solution};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+const ROUTE: &str = "`N`, `E`, `S`, `W`, `(`, `|` or `)`";
+
+/// Follows every route the regex allows from the starting room, recording
+/// each door passed through.
+pub fn parse(input: &str) -> Result<Facility> {
+    let regex = input.trim();
+    let err = |column: usize, text: &str, expected| Error::parse(Day20::DAY, column + 1, text, expected).at_line(1);
+
+    let body = regex.strip_prefix('^')
+        .ok_or_else(|| err(0, regex.get(..1).unwrap_or_default(), "`^`"))?;
+    let body = body.strip_suffix('$')
+        .ok_or_else(|| err(regex.len(), "", "`$` at the end"))?;
+
+    let mut facility = Facility::default();
+    let mut current = BTreeSet::new();
+    current.insert(Point::default());
+    // Each open group's starting rooms, and where its finished branches ended
+    let mut groups: Vec<(BTreeSet<Point>, BTreeSet<Point>)> = Vec::new();
+
+    for (index, c) in body.char_indices() {
+        let column = index + 1;
+        match c {
+            'N' | 'E' | 'S' | 'W' => {
+                let step = match c {
+                    'N' => Point::new(0, -1),
+                    'E' => Point::new(1, 0),
+                    'S' => Point::new(0, 1),
+                    _ => Point::new(-1, 0),
+                };
+                current = current.into_iter()
+                    .map(|room| {
+                        facility.add_door(room, room + step);
+                        room + step
+                    })
+                    .collect();
+            },
+            '(' => groups.push((current.clone(), BTreeSet::new())),
+            '|' => {
+                let (starts, ends) = groups.last_mut()
+                    .ok_or_else(|| err(column, "|", "a branch inside `(` and `)`"))?;
+                ends.append(&mut current);
+                current = starts.clone();
+            },
+            ')' => {
+                let (_, mut ends) = groups.pop()
+                    .ok_or_else(|| err(column, ")", "a `(` before it"))?;
+                current.append(&mut ends);
+            },
+            _ => return Err(err(column, &c.to_string(), ROUTE)),
+        }
+    }
+
+    if !groups.is_empty() {
+        return Err(err(regex.len() - 1, "$", "a `)` for every `(`"));
+    }
+
+    Ok(facility)
+}
+
+/// The most doors the shortest path to any room goes through.
+pub fn part1(facility: &Facility) -> u64 {
+    facility.distances().reached()
+        .map(|(_, doors)| doors)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Rooms at least a thousand doors away.
+pub fn part2(facility: &Facility) -> usize {
+    facility.distances().reached()
+        .filter(|&(_, doors)| doors >= 1000)
+        .count()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "A Regular Map";
+
+    type Input = Facility;
+
+    fn parse(input: &str) -> Result<Facility> {
+        parse(input)
+    }
+
+    fn part1(input: &Facility) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Facility) -> Answer {
+        part2(input).into()
+    }
+}
+
+/// The rooms, with the rooms each one has doors to. The starting room is 0,0.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Facility {
+    pub doors: BTreeMap<Point, Vec<(Point, u64)>>,
+}
+
+impl Facility {
+    pub fn add_door(&mut self, a: Point, b: Point) {
+        for (from, to) in [(a, b), (b, a)].iter() {
+            let doors = self.doors.entry(*from).or_default();
+            if !doors.contains(&(*to, 1)) {
+                doors.push((*to, 1));
+            }
+        }
+    }
+
+    pub fn distances(&self) -> Search<Point> {
+        path::bfs(&self.doors, Point::default())
+    }
+}
+
+#[test]
+fn furthest_room() -> Result<()> {
+    let examples = [
+        ("^WNE$", 3),
+        ("^ENWWW(NEEE|SSE(EE|N))$", 10),
+        ("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$", 18),
+        ("^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$", 23),
+        ("^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$", 31),
+    ];
+    for (regex, doors) in examples.iter() {
+        assert_eq!(part1(&parse(regex)?), *doors, "{}", regex);
+    }
+
+    let err = parse("^EN(W|S$").unwrap_err().to_string();
+    assert!(err.contains("column 8"), "{}", err);
+    assert!(parse("^EX$").is_err());
+    Ok(())
+}
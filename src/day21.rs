@@ -0,0 +1,175 @@
+//! Day 21: Chronal Conversion
+
+use crate::device::{Cpu, Opcode, Program};
+use crate::solution::{Answer, Result, Solution};
+
+use std::collections::HashSet;
+
+pub fn parse(input: &str) -> Result<Program> {
+    Program::parse(Day21::DAY, input)
+}
+
+/// The program only halts when an `eqrr` finds register 0 equal to the
+/// number it has just worked out. The first number it works out halts it
+/// soonest.
+pub fn part1(program: &Program) -> Option<usize> {
+    let (check, register) = halt_check(program)?;
+
+    let mut cpu = Cpu::new([0; 6]);
+    if cpu.run_until(program, |cpu| cpu.ip == check) {
+        Some(cpu.registers[register])
+    } else {
+        None
+    }
+}
+
+/// The numbers eventually repeat; the last new one halts it latest. Running
+/// the program that far takes billions of instructions, so this takes its
+/// seed and multiplier and works the numbers out directly.
+pub fn part2(program: &Program) -> Option<usize> {
+    let mut seen = HashSet::new();
+    let mut last = None;
+
+    for number in Generator::from_program(program)? {
+        if !seen.insert(number) {
+            break;
+        }
+        last = Some(number);
+    }
+
+    last
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Chronal Conversion";
+
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Program> {
+        parse(input)
+    }
+
+    fn part1(input: &Program) -> Answer {
+        match part1(input) {
+            Some(r0) => r0.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn part2(input: &Program) -> Answer {
+        match part2(input) {
+            Some(r0) => r0.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+/// Where the program compares a register with register 0, and which register.
+pub fn halt_check(program: &Program) -> Option<(usize, usize)> {
+    program.instructions.iter()
+        .position(|i| i.op == Opcode::Eqrr && (i.a == 0) != (i.b == 0))
+        .map(|index| {
+            let check = program.instructions[index];
+            (index, if check.a == 0 { check.b } else { check.a })
+        })
+}
+
+/// The numbers the program compares with register 0, in order. Each one
+/// hashes the last, a byte at a time, starting from the seed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Generator {
+    pub seed: usize,
+    pub multiplier: usize,
+    pub last: usize,
+}
+
+impl Generator {
+    /// Reads the seed from the `seti` after `bori _ 65536 _`, and the
+    /// multiplier from the first `muli`.
+    pub fn from_program(program: &Program) -> Option<Generator> {
+        let instructions = &program.instructions;
+
+        let seed = instructions.windows(2)
+            .find(|pair| pair[0].op == Opcode::Bori && pair[0].b == 65536 && pair[1].op == Opcode::Seti)
+            .map(|pair| pair[1].a)?;
+        let multiplier = instructions.iter()
+            .find(|i| i.op == Opcode::Muli)
+            .map(|i| i.b)?;
+
+        Some(Generator { seed, multiplier, last: 0 })
+    }
+}
+
+impl Iterator for Generator {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let mut bytes = self.last | 0x10000;
+        let mut number = self.seed;
+
+        while bytes > 0 {
+            // Wrapping, as the device does
+            number = ((number.wrapping_add(bytes & 0xFF) & 0xFF_FFFF).wrapping_mul(self.multiplier)) & 0xFF_FFFF;
+            bytes >>= 8;
+        }
+
+        self.last = number;
+        Some(number)
+    }
+}
+
+#[test]
+fn generator_matches_program() -> Result<()> {
+    let program = parse("\
+#ip 4
+seti 123 0 3
+bani 3 456 3
+eqri 3 72 3
+addr 3 4 4
+seti 0 0 4
+seti 0 9 3
+bori 3 65536 5
+seti 15028787 4 3
+bani 5 255 2
+addr 3 2 3
+bani 3 16777215 3
+muli 3 65899 3
+bani 3 16777215 3
+gtir 256 5 2
+addr 2 4 4
+addi 4 1 4
+seti 27 3 4
+seti 0 3 2
+addi 2 1 1
+muli 1 256 1
+gtrr 1 5 1
+addr 1 4 4
+addi 4 1 4
+seti 25 3 4
+addi 2 1 2
+seti 17 1 4
+setr 2 2 5
+seti 7 3 4
+eqrr 3 0 2
+addr 2 4 4
+seti 5 3 4
+")?;
+    assert_eq!(halt_check(&program), Some((28, 3)));
+
+    // The first few numbers, from running the program itself
+    let mut cpu = Cpu::new([0; 6]);
+    let mut numbers = Vec::new();
+    while numbers.len() < 3 && cpu.run_until(&program, |cpu| cpu.ip == 28) {
+        numbers.push(cpu.registers[3]);
+        cpu.step(&program);
+    }
+
+    let generator = Generator::from_program(&program).unwrap();
+    assert_eq!(generator.take(3).collect::<Vec<_>>(), numbers);
+    assert_eq!(part1(&program), Some(numbers[0]));
+    assert!(part2(&program).is_some());
+    Ok(())
+}
@@ -0,0 +1,179 @@
+//! Day 22: Mode Maze
+
+use crate::error::Error;
+use crate::grid::{Bounds, Grid, Point};
+use crate::parse;
+use crate::path::{self, Graph};
+use crate::solution::{Answer, Result, Solution};
+
+/// How far past the target the rescue might wander. The fastest route can
+/// detour around regions that need a tool change, but never this far.
+const MARGIN: i64 = 50;
+
+/// Minutes to move one region, and to change tools.
+const MOVE: u64 = 1;
+const SWITCH: u64 = 7;
+
+pub fn parse(input: &str) -> Result<Scan> {
+    let mut depth = None;
+    let mut target = None;
+
+    for (index, line) in input.lines().enumerate() {
+        let err = |column, text: &str, expected| Error::parse(Day22::DAY, column, text, expected).at_line(index + 1);
+
+        match parse::fields(line).as_slice() {
+            [] => (),
+            [(_, "depth:"), (column, value)] => {
+                depth = Some(value.parse::<u64>().map_err(|_| err(*column, value, "a depth like `510`"))?);
+            },
+            [(_, "target:"), (column, value)] => {
+                let (x, y) = parse::pair::<i64>(value, ',')
+                    .filter(|&(x, y)| x >= 0 && y >= 0)
+                    .ok_or_else(|| err(*column, value, "a target like `10,10`"))?;
+                target = Some(Point::new(x, y));
+            },
+            _ => return Err(err(1, line.trim(), "`depth: N` or `target: X,Y`")),
+        }
+    }
+
+    match (depth, target) {
+        (Some(depth), Some(target)) => {
+            let far = Point::new(target.x.saturating_add(MARGIN), target.y.saturating_add(MARGIN));
+            Bounds::new(Point::default(), far).limit(Day22::DAY)?;
+
+            Ok(Scan { depth, target })
+        },
+        _ => Err(Error::invalid(Day22::DAY, "the scan needs both a depth and a target")),
+    }
+}
+
+/// The total risk level of the rectangle from the mouth to the target.
+pub fn part1(scan: &Scan) -> u64 {
+    let cave = Cave::new(scan, 0);
+    cave.regions.values().map(|&region| region as u64).sum()
+}
+
+/// The fewest minutes to reach the target holding the torch, or `None` if
+/// the target is wet and the torch isn't allowed there.
+pub fn part2(scan: &Scan) -> Option<u64> {
+    let cave = Cave::new(scan, MARGIN);
+    let start = (Point::default(), Tool::Torch);
+    let goal = (scan.target, Tool::Torch);
+
+    path::astar(&cave, start, goal, |(point, _)| point.manhattan(scan.target) as u64 * MOVE)
+        .map(|(minutes, _)| minutes)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Mode Maze";
+
+    type Input = Scan;
+
+    fn parse(input: &str) -> Result<Scan> {
+        parse(input)
+    }
+
+    fn part1(input: &Scan) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Scan) -> Answer {
+        match part2(input) {
+            Some(minutes) => minutes.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scan {
+    pub depth: u64,
+    pub target: Point,
+}
+
+/// A region's type. Its number is also its risk level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Region {
+    Rocky = 0,
+    Wet = 1,
+    Narrow = 2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tool {
+    Torch,
+    ClimbingGear,
+    Neither,
+}
+
+impl Region {
+    pub fn allows(self, tool: Tool) -> bool {
+        !matches!(
+            (self, tool),
+            (Region::Rocky, Tool::Neither) | (Region::Wet, Tool::Torch) | (Region::Narrow, Tool::ClimbingGear)
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    pub regions: Grid<Region>,
+}
+
+impl Cave {
+    /// The cave from the mouth to `margin` regions past the target.
+    pub fn new(scan: &Scan, margin: i64) -> Cave {
+        let max = scan.target + Point::new(margin, margin);
+        let bounds = Bounds::new(Point::default(), max);
+
+        let mut erosion = Grid::new(bounds, 0_u64);
+        for point in bounds.points() {
+            let index = match point {
+                Point { x: 0, y: 0 } => 0,
+                _ if point == scan.target => 0,
+                Point { x, y: 0 } => x as u64 * 16807,
+                Point { x: 0, y } => y as u64 * 48271,
+                _ => erosion[point - Point::new(1, 0)] * erosion[point - Point::new(0, 1)],
+            };
+            erosion[point] = (index + scan.depth % 20183) % 20183;
+        }
+
+        let regions = Grid::from_fn(bounds, |point| match erosion[point] % 3 {
+            0 => Region::Rocky,
+            1 => Region::Wet,
+            _ => Region::Narrow,
+        });
+
+        Cave { regions }
+    }
+}
+
+impl Graph for Cave {
+    type Node = (Point, Tool);
+
+    fn neighbours(&self, (point, tool): (Point, Tool)) -> Vec<((Point, Tool), u64)> {
+        let region = self.regions[point];
+
+        let moves = self.regions.neighbours4(point)
+            .filter(|&n| self.regions[n].allows(tool))
+            .map(|n| ((n, tool), MOVE));
+        let switches = [Tool::Torch, Tool::ClimbingGear, Tool::Neither].iter()
+            .filter(|&&other| other != tool && region.allows(other))
+            .map(|&other| ((point, other), SWITCH));
+
+        moves.chain(switches).collect()
+    }
+}
+
+#[test]
+fn mode_maze() -> Result<()> {
+    let scan = parse("depth: 510\ntarget: 10,10\n")?;
+    assert_eq!(part1(&scan), 114);
+    assert_eq!(part2(&scan), Some(45));
+
+    assert!(parse("depth: 510\n").is_err());
+    Ok(())
+}
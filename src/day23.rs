@@ -0,0 +1,209 @@
+//! Day 23: Experimental Emergency Teleportation
+
+use crate::budget;
+use crate::error::Error;
+use crate::parse;
+use crate::solution::{Answer, Result, Solution};
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::str::FromStr;
+
+pub fn parse(input: &str) -> Result<Vec<Nanobot>> {
+    let bots = parse::lines(input, Nanobot::from_str)?;
+    if bots.is_empty() {
+        return Err(Error::invalid(Day23::DAY, "no nanobots"));
+    }
+
+    Ok(bots)
+}
+
+/// How many bots are in range of the one with the longest range.
+pub fn part1(bots: &[Nanobot]) -> usize {
+    let strongest = match bots.iter().max_by_key(|bot| bot.radius) {
+        Some(bot) => bot,
+        None => return 0,
+    };
+
+    bots.iter()
+        .filter(|bot| strongest.reaches(bot.pos))
+        .count()
+}
+
+/// The distance from the origin to the closest point in range of the most
+/// bots.
+///
+/// Searches boxes, starting with one around every bot: the box in range of
+/// the most bots is split into eight, until the best box is a single point.
+/// A box's count is at least as high as any point in it, so the first point
+/// to come out on top is the answer.
+pub fn part2(bots: &[Nanobot]) -> i64 {
+    let reach = bots.iter()
+        .flat_map(|bot| bot.pos.iter().map(move |c| c.abs() + bot.radius))
+        .max()
+        .unwrap_or(0);
+    let mut size = 1;
+    while size < reach * 2 + 1 {
+        size *= 2;
+    }
+
+    let mut heap = BinaryHeap::new();
+    let start = Cube { min: [-size / 2; 3], size };
+    heap.push((start.in_range(bots), Reverse(start.distance([0; 3])), Reverse(size), start));
+
+    while let Some((_, Reverse(distance), Reverse(size), cube)) = heap.pop() {
+        if size == 1 || !budget::step() {
+            return distance;
+        }
+
+        for octant in cube.split() {
+            heap.push((octant.in_range(bots), Reverse(octant.distance([0; 3])), Reverse(octant.size), octant));
+        }
+    }
+
+    0
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Experimental Emergency Teleportation";
+
+    type Input = Vec<Nanobot>;
+
+    fn parse(input: &str) -> Result<Vec<Nanobot>> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<Error> {
+        parse::check_lines(input, Nanobot::from_str)
+    }
+
+    fn part1(input: &Vec<Nanobot>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Vec<Nanobot>) -> Answer {
+        part2(input).into()
+    }
+}
+
+pub type Pos = [i64; 3];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nanobot {
+    pub pos: Pos,
+    pub radius: i64,
+}
+
+impl Nanobot {
+    pub fn reaches(&self, pos: Pos) -> bool {
+        distance(self.pos, pos) <= self.radius
+    }
+}
+
+pub fn distance(a: Pos, b: Pos) -> i64 {
+    a.iter().zip(b.iter()).map(|(a, b)| (a - b).abs()).sum()
+}
+
+impl FromStr for Nanobot {
+    type Err = Error;
+
+    /// Parses `pos=<0,0,0>, r=4`.
+    fn from_str(s: &str) -> Result<Nanobot> {
+        const NANOBOT: &str = "a nanobot like `pos=<0,0,0>, r=4`";
+        let err = || Error::parse(Day23::DAY, 1, s.trim(), NANOBOT);
+
+        let (pos, radius) = s.trim()
+            .strip_prefix("pos=<")
+            .and_then(|rest| rest.split_once(">, r="))
+            .ok_or_else(err)?;
+
+        // Real coordinates are in the hundreds of millions; keeping to `i32`
+        // leaves room to search around them
+        let coords = pos.split(',')
+            .map(|c| c.trim().parse::<i32>().map(i64::from))
+            .collect::<std::result::Result<Vec<i64>, _>>()
+            .ok()
+            .filter(|coords| coords.len() == 3)
+            .ok_or_else(|| Error::parse(Day23::DAY, 6, pos, "three coordinates like `0,0,0`"))?;
+        let radius = radius.parse::<i32>().ok().map(i64::from)
+            .filter(|&r| r >= 0)
+            .ok_or_else(|| Error::parse(Day23::DAY, pos.len() + 11, radius, "a range like `4`"))?;
+
+        Ok(Nanobot { pos: [coords[0], coords[1], coords[2]], radius })
+    }
+}
+
+/// A cube of points, `size` along each side from `min`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Cube {
+    pub min: Pos,
+    pub size: i64,
+}
+
+impl Cube {
+    /// The distance from `pos` to the nearest point in the cube.
+    pub fn distance(&self, pos: Pos) -> i64 {
+        (0..3)
+            .map(|axis| {
+                let (low, high) = (self.min[axis], self.min[axis] + self.size - 1);
+                (low - pos[axis]).max(0) + (pos[axis] - high).max(0)
+            })
+            .sum()
+    }
+
+    /// How many bots reach some point of the cube.
+    pub fn in_range(&self, bots: &[Nanobot]) -> usize {
+        bots.iter()
+            .filter(|bot| self.distance(bot.pos) <= bot.radius)
+            .count()
+    }
+
+    pub fn split(&self) -> Vec<Cube> {
+        let half = self.size / 2;
+
+        (0..8)
+            .map(|corner| {
+                let mut min = self.min;
+                for (axis, c) in min.iter_mut().enumerate() {
+                    if corner & (1 << axis) != 0 {
+                        *c += half;
+                    }
+                }
+                Cube { min, size: half }
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn teleportation() -> Result<()> {
+    let bots = parse("\
+pos=<0,0,0>, r=4
+pos=<1,0,0>, r=1
+pos=<4,0,0>, r=3
+pos=<0,2,0>, r=1
+pos=<0,5,0>, r=3
+pos=<0,0,3>, r=1
+pos=<1,1,1>, r=1
+pos=<1,1,2>, r=1
+pos=<1,3,1>, r=1
+")?;
+    assert_eq!(part1(&bots), 7);
+
+    let bots = parse("\
+pos=<10,12,12>, r=2
+pos=<12,14,12>, r=2
+pos=<16,12,12>, r=4
+pos=<14,14,14>, r=6
+pos=<50,50,50>, r=200
+pos=<10,10,10>, r=5
+")?;
+    assert_eq!(part2(&bots), 36);
+
+    let err = parse("pos=<1,x,3>, r=2").unwrap_err().to_string();
+    assert!(err.contains("column 6"), "{}", err);
+    Ok(())
+}
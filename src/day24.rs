@@ -0,0 +1,271 @@
+//! Day 24: Immune System Simulator 20XX
+
+use crate::budget;
+use crate::error::Error;
+use crate::parse;
+use crate::solution::{Answer, Result, Solution};
+
+use std::str::FromStr;
+
+const GROUP: &str = "a group like `17 units each with 5390 hit points (weak to fire) \
+with an attack that does 4507 fire damage at initiative 2`";
+
+pub fn parse(input: &str) -> Result<Vec<Group>> {
+    let mut groups = Vec::new();
+    let mut army = None;
+
+    for (index, line) in input.lines().enumerate() {
+        match line.trim() {
+            "" => (),
+            "Immune System:" => army = Some(Army::ImmuneSystem),
+            "Infection:" => army = Some(Army::Infection),
+            text => {
+                let army = army.ok_or_else(|| {
+                    Error::parse(Day24::DAY, 1, text, "`Immune System:` or `Infection:`").at_line(index + 1)
+                })?;
+                let group = Group::from_str(line).map_err(|e| e.at_line(index + 1))?;
+                groups.push(Group { army, ..group });
+            },
+        }
+    }
+
+    Ok(groups)
+}
+
+/// The units the winning army has left.
+pub fn part1(groups: &[Group]) -> Option<usize> {
+    fight(groups.to_vec()).map(|(_, units)| units)
+}
+
+/// The immune system's units left after the smallest boost that lets it win.
+pub fn part2(groups: &[Group]) -> Option<usize> {
+    // By a boost as big as the sturdiest group's hit points, every attack the
+    // immune system makes kills; give up there.
+    let most = groups.iter().map(|g| g.hit_points).max().unwrap_or(0);
+
+    (1..=most)
+        .take_while(|_| budget::step())
+        .filter_map(|boost| {
+            let boosted = groups.iter()
+                .map(|g| match g.army {
+                    Army::ImmuneSystem => Group { damage: g.damage.saturating_add(boost), ..g.clone() },
+                    Army::Infection => g.clone(),
+                })
+                .collect();
+            fight(boosted)
+        })
+        .find(|&(winner, _)| winner == Army::ImmuneSystem)
+        .map(|(_, units)| units)
+}
+
+/// Runs the fight to the end. Returns the winning army and its units, or
+/// `None` if it reaches a stalemate where nobody can kill anyone, or runs
+/// out of budget.
+pub fn fight(mut groups: Vec<Group>) -> Option<(Army, usize)> {
+    while budget::step() {
+        groups.retain(|g| g.units > 0);
+
+        let immune = groups.iter().any(|g| g.army == Army::ImmuneSystem);
+        let infection = groups.iter().any(|g| g.army == Army::Infection);
+        match (immune, infection) {
+            (true, true) => (),
+            (false, false) => return None,
+            _ => {
+                let winner = if immune { Army::ImmuneSystem } else { Army::Infection };
+                return Some((winner, groups.iter().map(|g| g.units).fold(0, usize::saturating_add)));
+            },
+        }
+
+        let targets = select_targets(&groups);
+
+        let mut order = (0..groups.len()).collect::<Vec<usize>>();
+        order.sort_by_key(|&i| std::cmp::Reverse(groups[i].initiative));
+
+        let mut killed = 0;
+        for attacker in order {
+            if let Some(target) = targets[attacker] {
+                let damage = groups[attacker].damage_to(&groups[target]);
+                let kills = (damage / groups[target].hit_points).min(groups[target].units);
+                groups[target].units -= kills;
+                killed += kills;
+            }
+        }
+
+        if killed == 0 {
+            return None;
+        }
+    }
+
+    None
+}
+
+/// Each group's target for this round, by index. Groups choose in order of
+/// effective power, then initiative, picking the enemy they'd hurt most.
+fn select_targets(groups: &[Group]) -> Vec<Option<usize>> {
+    let mut order = (0..groups.len()).collect::<Vec<usize>>();
+    order.sort_by_key(|&i| std::cmp::Reverse((groups[i].effective_power(), groups[i].initiative)));
+
+    let mut targets = vec![None; groups.len()];
+    let mut taken = vec![false; groups.len()];
+
+    for attacker in order {
+        let group = &groups[attacker];
+        let target = (0..groups.len())
+            .filter(|&t| !taken[t] && groups[t].army != group.army && group.damage_to(&groups[t]) > 0)
+            .max_by_key(|&t| (group.damage_to(&groups[t]), groups[t].effective_power(), groups[t].initiative));
+
+        if let Some(target) = target {
+            taken[target] = true;
+            targets[attacker] = Some(target);
+        }
+    }
+
+    targets
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Immune System Simulator 20XX";
+
+    type Input = Vec<Group>;
+
+    fn parse(input: &str) -> Result<Vec<Group>> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<Error> {
+        parse::check_lines(input, |line| match line.trim() {
+            "Immune System:" | "Infection:" => Ok(()),
+            _ => Group::from_str(line).map(|_| ()),
+        })
+    }
+
+    fn part1(input: &Vec<Group>) -> Answer {
+        match part1(input) {
+            Some(units) => units.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn part2(input: &Vec<Group>) -> Answer {
+        match part2(input) {
+            Some(units) => units.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Army {
+    ImmuneSystem,
+    Infection,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    pub army: Army,
+    pub units: usize,
+    pub hit_points: usize,
+    pub weaknesses: Vec<String>,
+    pub immunities: Vec<String>,
+    pub damage: usize,
+    pub attack: String,
+    pub initiative: usize,
+}
+
+impl Group {
+    /// Saturates, so absurdly big groups still fight.
+    pub fn effective_power(&self) -> usize {
+        self.units.saturating_mul(self.damage)
+    }
+
+    /// What this group would do to `other`, allowing for weaknesses and
+    /// immunities.
+    pub fn damage_to(&self, other: &Group) -> usize {
+        if other.immunities.contains(&self.attack) {
+            0
+        } else if other.weaknesses.contains(&self.attack) {
+            self.effective_power().saturating_mul(2)
+        } else {
+            self.effective_power()
+        }
+    }
+}
+
+impl FromStr for Group {
+    type Err = Error;
+
+    /// Parses a group, leaving it in the immune system; the army comes from
+    /// the heading above it.
+    fn from_str(s: &str) -> Result<Group> {
+        let err = || Error::parse(Day24::DAY, 1, s.trim(), GROUP);
+        let number = |(column, text): (usize, &str)| text.parse::<usize>()
+            .map_err(|_| Error::parse(Day24::DAY, column, text, "a whole number"));
+
+        let (before, rest) = s.split_once(" hit points").ok_or_else(err)?;
+        let (modifiers, attack) = match rest.trim_start().strip_prefix('(') {
+            Some(rest) => rest.split_once(')').ok_or_else(err)?,
+            None => ("", rest),
+        };
+
+        let mut weaknesses = Vec::new();
+        let mut immunities = Vec::new();
+        for modifier in modifiers.split(';').map(str::trim).filter(|m| !m.is_empty()) {
+            let (kinds, list) = if let Some(list) = modifier.strip_prefix("weak to ") {
+                (&mut weaknesses, list)
+            } else if let Some(list) = modifier.strip_prefix("immune to ") {
+                (&mut immunities, list)
+            } else {
+                return Err(err());
+            };
+            kinds.extend(list.split(',').map(|kind| kind.trim().to_string()));
+        }
+
+        let offset = s.len() - attack.len();
+        let head = parse::fields(before);
+        let tail = parse::fields(attack);
+
+        match (head.as_slice(), tail.as_slice()) {
+            (
+                [units, (_, "units"), (_, "each"), (_, "with"), hit_points],
+                [(_, "with"), (_, "an"), (_, "attack"), (_, "that"), (_, "does"), damage, (_, kind), (_, "damage"), (_, "at"), (_, "initiative"), initiative],
+            ) => Ok(Group {
+                army: Army::ImmuneSystem,
+                units: number(*units)?,
+                hit_points: number(*hit_points)?.max(1),
+                weaknesses,
+                immunities,
+                damage: number(*damage).map_err(|e| e.offset(offset))?,
+                attack: kind.to_string(),
+                initiative: number(*initiative).map_err(|e| e.offset(offset))?,
+            }),
+            _ => Err(err()),
+        }
+    }
+}
+
+#[test]
+fn immune_system() -> Result<()> {
+    let groups = parse("\
+Immune System:
+17 units each with 5390 hit points (weak to radiation, bludgeoning) with an attack that does 4507 fire damage at initiative 2
+989 units each with 1274 hit points (immune to fire; weak to bludgeoning, slashing) with an attack that does 25 slashing damage at initiative 3
+
+Infection:
+801 units each with 4706 hit points (weak to radiation) with an attack that does 116 bludgeoning damage at initiative 1
+4485 units each with 2961 hit points (immune to radiation; weak to fire, cold) with an attack that does 12 slashing damage at initiative 4
+")?;
+    assert_eq!(groups[1].immunities, vec!["fire"]);
+    assert_eq!(groups[3].army, Army::Infection);
+
+    assert_eq!(part1(&groups), Some(5216));
+    assert_eq!(part2(&groups), Some(51));
+
+    let err = parse("Infection:\n801 units each with 4706 hit points with an attack that does lots bludgeoning damage at initiative 1")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("line 2, column 62"), "{}", err);
+    Ok(())
+}
@@ -0,0 +1,100 @@
+//! Day 25: Four-Dimensional Adventure
+
+use crate::error::Error;
+use crate::parse;
+use crate::solution::{Answer, Result, Solution};
+
+pub type Point4 = [i64; 4];
+
+pub fn parse(input: &str) -> Result<Vec<Point4>> {
+    parse::lines(input, point)
+}
+
+/// Parses `0,0,0,0`.
+pub fn point(s: &str) -> Result<Point4> {
+    let mut point = [0; 4];
+    let mut coords = s.trim().split(',');
+    let mut column = 1 + s.len() - s.trim_start().len();
+
+    for c in point.iter_mut() {
+        let text = coords.next().unwrap_or_default();
+        *c = text.trim().parse()
+            .map_err(|_| Error::parse(Day25::DAY, column, text, "four coordinates like `0,-3,2,1`"))?;
+        column += text.len() + 1;
+    }
+
+    if let Some(text) = coords.next() {
+        return Err(Error::parse(Day25::DAY, column - 1, &format!(",{}", text), "the end of the line"));
+    }
+
+    Ok(point)
+}
+
+/// Points within three of each other share a constellation, and so do
+/// their constellations. Counts them with a disjoint set.
+pub fn part1(points: &[Point4]) -> usize {
+    let mut parent = (0..points.len()).collect::<Vec<usize>>();
+
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+
+    for (i, a) in points.iter().enumerate() {
+        for (j, b) in points.iter().enumerate().skip(i + 1) {
+            if distance(*a, *b) <= 3 {
+                let (ra, rb) = (root(&mut parent, i), root(&mut parent, j));
+                parent[ra] = rb;
+            }
+        }
+    }
+
+    (0..points.len()).filter(|&i| root(&mut parent, i) == i).count()
+}
+
+pub fn distance(a: Point4, b: Point4) -> i64 {
+    a.iter().zip(b.iter()).map(|(a, b)| (a - b).abs()).sum()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Four-Dimensional Adventure";
+    // There's no second puzzle on the last day
+    const PARTS: u8 = 1;
+
+    type Input = Vec<Point4>;
+
+    fn parse(input: &str) -> Result<Vec<Point4>> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<Error> {
+        parse::check_lines(input, point)
+    }
+
+    fn part1(input: &Vec<Point4>) -> Answer {
+        part1(input).into()
+    }
+}
+
+#[test]
+fn constellations() -> Result<()> {
+    let examples = [
+        (" 0,0,0,0\n 3,0,0,0\n 0,3,0,0\n 0,0,3,0\n 0,0,0,3\n 0,0,0,6\n 9,0,0,0\n12,0,0,0\n", 2),
+        ("-1,2,2,0\n0,0,2,-2\n0,0,0,-2\n-1,2,0,0\n-2,-2,-2,2\n3,0,2,-1\n-1,3,2,2\n-1,0,-1,0\n0,2,1,-2\n3,0,0,0\n", 4),
+        ("1,-1,0,1\n2,0,-1,0\n3,2,-1,0\n0,0,3,1\n0,0,-1,-1\n2,3,-2,0\n-2,2,0,0\n2,-2,0,-1\n1,-1,0,-1\n3,2,0,2\n", 3),
+        ("1,-1,-1,-2\n-2,-2,0,1\n0,2,1,3\n-2,3,-2,1\n0,2,3,-2\n-1,-1,1,-2\n0,-2,-1,0\n-2,2,3,-1\n1,2,2,0\n-1,-2,0,-2\n", 8),
+    ];
+    for (points, count) in examples.iter() {
+        assert_eq!(part1(&parse(points)?), *count);
+    }
+
+    let err = parse("0,0,x,0").unwrap_err().to_string();
+    assert!(err.contains("line 1, column 5"), "{}", err);
+    Ok(())
+}
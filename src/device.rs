@@ -0,0 +1,240 @@
+//! The wrist device from days 16, 19 and 21: sixteen opcodes working on a
+//! few registers, and programs that bind the instruction pointer to one of
+//! them.
+
+use crate::budget;
+use crate::error::{Error, Result};
+use crate::parse;
+
+use std::fmt;
+
+/// Day 19 and 21 programs use six registers; day 16 only the first four.
+pub type Registers = [usize; 6];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Opcode {
+    Addr, Addi,
+    Mulr, Muli,
+    Banr, Bani,
+    Borr, Bori,
+    Setr, Seti,
+    Gtir, Gtri, Gtrr,
+    Eqir, Eqri, Eqrr,
+}
+
+use Opcode::*;
+
+impl Opcode {
+    pub const ALL: [Opcode; 16] = [
+        Addr, Addi, Mulr, Muli, Banr, Bani, Borr, Bori,
+        Setr, Seti, Gtir, Gtri, Gtrr, Eqir, Eqri, Eqrr,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Addr => "addr", Addi => "addi",
+            Mulr => "mulr", Muli => "muli",
+            Banr => "banr", Bani => "bani",
+            Borr => "borr", Bori => "bori",
+            Setr => "setr", Seti => "seti",
+            Gtir => "gtir", Gtri => "gtri", Gtrr => "gtrr",
+            Eqir => "eqir", Eqri => "eqri", Eqrr => "eqrr",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Opcode> {
+        Opcode::ALL.iter().copied().find(|op| op.name() == name)
+    }
+
+    /// Runs the opcode on `registers`, or returns `None` if an operand names
+    /// a register there isn't.
+    pub fn execute(self, a: usize, b: usize, c: usize, registers: &mut [usize]) -> Option<()> {
+        let reg = |r: usize| registers.get(r).copied();
+
+        let value = match self {
+            Addr => reg(a)?.wrapping_add(reg(b)?),
+            Addi => reg(a)?.wrapping_add(b),
+            Mulr => reg(a)?.wrapping_mul(reg(b)?),
+            Muli => reg(a)?.wrapping_mul(b),
+            Banr => reg(a)? & reg(b)?,
+            Bani => reg(a)? & b,
+            Borr => reg(a)? | reg(b)?,
+            Bori => reg(a)? | b,
+            Setr => reg(a)?,
+            Seti => a,
+            Gtir => (a > reg(b)?) as usize,
+            Gtri => (reg(a)? > b) as usize,
+            Gtrr => (reg(a)? > reg(b)?) as usize,
+            Eqir => (a == reg(b)?) as usize,
+            Eqri => (reg(a)? == b) as usize,
+            Eqrr => (reg(a)? == reg(b)?) as usize,
+        };
+
+        *registers.get_mut(c)? = value;
+        Some(())
+    }
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub op: Opcode,
+    pub a: usize,
+    pub b: usize,
+    pub c: usize,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.op, self.a, self.b, self.c)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    /// The register the instruction pointer is bound to, from `#ip N`
+    pub ip: Option<usize>,
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    /// Parses an optional `#ip N` line followed by lines like `seti 5 0 1`.
+    pub fn parse(day: u8, input: &str) -> Result<Program> {
+        const INSTRUCTION: &str = "an instruction like `seti 5 0 1`";
+        const REGISTER: &str = "a register from 0 to 5";
+
+        let mut ip = None;
+        let mut instructions = Vec::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let at_line = |e: Error| e.at_line(index + 1);
+
+            match parse::fields(line).as_slice() {
+                [] => (),
+                [(_, "#ip"), (column, register)] if ip.is_none() && instructions.is_empty() => {
+                    let register = register.parse::<usize>().ok()
+                        .filter(|&r| r < 6)
+                        .ok_or_else(|| at_line(Error::parse(day, *column, register, REGISTER)))?;
+                    ip = Some(register);
+                },
+                [(column, name), operands @ ..] if operands.len() == 3 => {
+                    let op = Opcode::from_name(name)
+                        .ok_or_else(|| at_line(Error::parse(day, *column, name, INSTRUCTION)))?;
+
+                    let mut values = [0; 3];
+                    for (value, (column, text)) in values.iter_mut().zip(operands) {
+                        *value = text.parse()
+                            .map_err(|_| at_line(Error::parse(day, *column, text, "a whole number")))?;
+                    }
+
+                    let [a, b, c] = values;
+                    if c >= 6 {
+                        return Err(at_line(Error::parse(day, operands[2].0, operands[2].1, REGISTER)));
+                    }
+                    instructions.push(Instruction { op, a, b, c });
+                },
+                _ => return Err(at_line(Error::parse(day, 1, line.trim(), INSTRUCTION))),
+            }
+        }
+
+        if instructions.is_empty() {
+            return Err(Error::invalid(day, "the program has no instructions"));
+        }
+
+        Ok(Program { ip, instructions })
+    }
+}
+
+/// A running program's registers and instruction pointer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cpu {
+    pub registers: Registers,
+    pub ip: usize,
+}
+
+impl Cpu {
+    pub fn new(registers: Registers) -> Cpu {
+        Cpu { registers, ip: 0 }
+    }
+
+    /// The instruction about to run, or `None` once the program has halted.
+    pub fn instruction<'a>(&self, program: &'a Program) -> Option<&'a Instruction> {
+        program.instructions.get(self.ip)
+    }
+
+    /// Runs one instruction. Returns false if the program had already halted.
+    pub fn step(&mut self, program: &Program) -> bool {
+        let instruction = match self.instruction(program) {
+            Some(instruction) => *instruction,
+            None => return false,
+        };
+
+        if let Some(bound) = program.ip {
+            self.registers[bound] = self.ip;
+        }
+
+        let Instruction { op, a, b, c } = instruction;
+        // Parsing checked `c`; an out of range `a` or `b` reads as a halt
+        if op.execute(a, b, c, &mut self.registers).is_none() {
+            self.ip = program.instructions.len();
+            return false;
+        }
+
+        if let Some(bound) = program.ip {
+            self.ip = self.registers[bound];
+        }
+        self.ip = self.ip.wrapping_add(1);
+
+        true
+    }
+
+    /// Runs until the program halts, or the budget runs out.
+    pub fn run(&mut self, program: &Program) {
+        while self.step(program) && budget::step() {}
+    }
+
+    /// Runs until `stop` holds before an instruction. Returns false if the
+    /// program halted first, or the budget ran out.
+    pub fn run_until<F: Fn(&Cpu) -> bool>(&mut self, program: &Program, stop: F) -> bool {
+        loop {
+            if self.instruction(program).is_none() || !budget::step() {
+                return false;
+            }
+            if stop(self) {
+                return true;
+            }
+            self.step(program);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opcodes() {
+        let mut registers = [3, 2, 1, 1];
+        Mulr.execute(2, 1, 2, &mut registers).unwrap();
+        assert_eq!(registers, [3, 2, 2, 1]);
+
+        assert_eq!(Gtir.execute(9, 4, 0, &mut registers), None);
+        assert_eq!(Opcode::from_name("eqri"), Some(Eqri));
+    }
+
+    #[test]
+    fn bound_instruction_pointer() -> Result<()> {
+        let program = Program::parse(19, "#ip 0\nseti 5 0 1\nseti 6 0 2\naddi 0 1 0\naddr 1 2 3\nsetr 1 0 0\nseti 8 0 4\nseti 9 0 5\n")?;
+        let mut cpu = Cpu::new([0; 6]);
+        cpu.run(&program);
+        assert_eq!(cpu.registers, [6, 5, 6, 0, 0, 9]);
+
+        assert!(Program::parse(19, "#ip 0\nseti 5 0 9\n").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// Puzzle input that doesn't match the day's grammar. `line` and `column`
+    /// count from 1; `line` is 0 until the caller knows which line it was.
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        text: String,
+        expected: &'static str,
+    },
+    /// Input that parses but can't be solved, like a map without any points
+    Invalid {
+        day: u8,
+        reason: String,
+    },
+    /// A solver that ran out of its [`budget`](crate::budget)
+    Timeout {
+        steps: u64,
+        after: Duration,
+    },
+    Io(io::Error),
+}
+
+impl Error {
+    pub fn parse(day: u8, column: usize, text: &str, expected: &'static str) -> Error {
+        Error::Parse {
+            day,
+            line: 0,
+            column,
+            text: text.to_string(),
+            expected,
+        }
+    }
+
+    pub fn invalid<S: Into<String>>(day: u8, reason: S) -> Error {
+        Error::Invalid { day, reason: reason.into() }
+    }
+
+    /// Stamps a parse error with the line it came from, unless it already has one.
+    pub fn at_line(self, line: usize) -> Error {
+        match self {
+            Error::Parse { day, line: 0, column, text, expected } =>
+                Error::Parse { day, line, column, text, expected },
+            other => other,
+        }
+    }
+
+    /// Moves a parse error's column right, for errors found in part of a line.
+    pub fn offset(self, columns: usize) -> Error {
+        match self {
+            Error::Parse { day, line, column, text, expected } =>
+                Error::Parse { day, line, column: column + columns, text, expected },
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse { day, line, column, text, expected } => {
+                write!(f, "Day {}, ", day)?;
+                if *line > 0 {
+                    write!(f, "line {}, ", line)?;
+                }
+                write!(f, "column {}: expected {}, found `{}`", column, expected, text)
+            },
+            Error::Invalid { day, reason } => write!(f, "Day {}: {}", day, reason),
+            Error::Timeout { steps, after } => write!(f, "timed out after {} steps ({:?})", steps, after),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+#[test]
+fn display() {
+    let e = Error::parse(3, 6, "1,x:", "a claim like `#1 @ 1,3: 4x4`").at_line(12);
+    assert_eq!(
+        e.to_string(),
+        "Day 3, line 12, column 6: expected a claim like `#1 @ 1,3: 4x4`, found `1,x:`"
+    );
+
+    let e = Error::parse(5, 1, "?", "a unit").offset(2).at_line(1).at_line(7);
+    assert_eq!(e.to_string(), "Day 5, line 1, column 3: expected a unit, found `?`");
+}
@@ -0,0 +1,135 @@
+//! What the fuzz targets in `fuzz/` run, one per day: whatever bytes they're
+//! given, checking and solving has to end in an answer or an error, never a
+//! panic or a hang.
+//!
+//! The targets need a nightly toolchain and `cargo fuzz`; [`mutate`] and the
+//! tests below do a much smaller version of the same on stable.
+
+use crate::*;
+use crate::gen::Rng;
+
+use std::str;
+use std::time::Duration;
+
+/// How long each part gets on one input. Anything slower is reported as a
+/// timeout, which is fine.
+pub const BUDGET: Duration = Duration::from_millis(200);
+
+/// Checks `data` as input for `day`, then solves both parts of it.
+pub fn run(day: u8, data: &[u8]) {
+    let input = match str::from_utf8(data) {
+        Ok(input) => input,
+        Err(_) => return,
+    };
+
+    let day = match registry::get(day) {
+        Some(day) => day,
+        None => return,
+    };
+
+    let _ = (day.check)(input);
+    for part in (1..=2).filter_map(|part| day.part(part)) {
+        let _ = budget::limit(BUDGET, || part(input));
+    }
+}
+
+/// Bits of input that have broken parsers and solvers before.
+const SNIPPETS: &[&str] = &[
+    "", "\n", " ", "0", "-1", "+", "-", "#", ".", ",", "@", ":", "x", "=", "<", ">",
+    "99999999999999999999", "-9223372036854775808", "4294967296", "18446744073709551615",
+    "é", "\u{0}", "\r\n",
+];
+
+/// A small random change to `input`, the way a fuzzer would make one.
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut lines = input.lines().map(String::from).collect::<Vec<String>>();
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    let line = rng.index(lines.len());
+    match rng.range(0, 7) {
+        0 => lines.truncate(line),
+        1 => {
+            lines.remove(line);
+        },
+        2 => {
+            let copy = lines[line].clone();
+            lines.insert(line, copy);
+        },
+        3 => {
+            let other = rng.index(lines.len());
+            lines.swap(line, other);
+        },
+        // Cut a line short, or splice something into it
+        mutation => {
+            let chars = lines[line].chars().collect::<Vec<char>>();
+            let at = rng.index(chars.len() + 1);
+            let end = if mutation == 4 { chars.len() } else { (at + rng.index(3)).min(chars.len()) };
+            let snippet = if mutation == 4 { "" } else { rng.pick(SNIPPETS) };
+
+            lines[line] = chars[..at].iter().collect::<String>() + snippet + &chars[end..].iter().collect::<String>();
+        },
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use std::panic::{self, AssertUnwindSafe};
+
+    /// The examples for `day`, and a couple of generated inputs. The real
+    /// inputs take too long to solve over and over.
+    fn seeds(day: u8) -> Vec<String> {
+        let prefix = format!("day{:02}_example", day);
+        let mut seeds = fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/input"))
+            .expect("the input directory")
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.file_name().and_then(|n| n.to_str()).is_some_and(|n| n.starts_with(&prefix)))
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .collect::<Vec<String>>();
+
+        seeds.extend((0..2).filter_map(|seed| gen::input(day, seed)));
+        seeds
+    }
+
+    #[test]
+    fn past_crashes_stay_fixed() {
+        let crashes: &[(u8, &str)] = &[
+            (1, "-9223372036854775808\n-1\n"),
+        ];
+        for &(day, input) in crashes {
+            run(day, input.as_bytes());
+        }
+    }
+
+    #[test]
+    fn mutated_inputs_never_panic() {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+
+        let mut panics = Vec::new();
+        for day in registry::DAYS.iter().map(|d| d.day) {
+            let rng = &mut Rng::new(u64::from(day));
+            for seed in seeds(day) {
+                let mut input = seed.clone();
+                for _ in 0..12 {
+                    input = mutate(rng, &input);
+                    if panic::catch_unwind(AssertUnwindSafe(|| run(day, input.as_bytes()))).is_err() {
+                        panics.push((day, input.clone()));
+                    }
+                    if rng.one_in(4) {
+                        input = seed.clone();
+                    }
+                }
+            }
+        }
+
+        panic::set_hook(hook);
+        assert!(panics.is_empty(), "{} inputs panicked, first on day {}:\n{}", panics.len(), panics[0].0, panics[0].1);
+    }
+}
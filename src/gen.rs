@@ -0,0 +1,482 @@
+//! Random puzzle inputs, for stress-testing the solvers beyond the one real
+//! input each day has. Every generator is seeded, so an input that breaks a
+//! solver can be made again with `aoc gen <day> --seed <seed>`.
+//!
+//! Inputs are shaped like the real ones, and built so that the puzzle has an
+//! answer: day 2 has exactly one pair of nearly matching IDs, day 3 exactly
+//! one claim that overlaps no other, and so on.
+
+use crate::day04::{Event, Record};
+use crate::day10;
+use crate::grid::{Bounds, Grid, Point};
+use crate::path;
+
+use chrono::{Duration, NaiveDate};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The days that have a generator.
+pub const DAYS: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 10, 12, 13, 15];
+
+/// A random input for `day`, the same every time for the same seed.
+pub fn input(day: u8, seed: u64) -> Option<String> {
+    let rng = &mut Rng::new(seed);
+
+    let input = match day {
+        1 => day01(rng),
+        2 => day02(rng),
+        3 => day03(rng),
+        4 => day04(rng),
+        5 => day05(rng),
+        6 => day06(rng),
+        7 => day07(rng),
+        10 => day10(rng),
+        12 => day12(rng),
+        13 => day13(rng),
+        15 => day15(rng),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+/// A small splitmix64 generator. Not much good for anything but this, but
+/// unlike an external crate its sequences won't change under us.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number from `low` up to, but not including, `high`.
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low < high, "empty range {}..{}", low, high);
+        low + (self.next_u64() % (high - low) as u64) as i64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0, len as i64) as usize
+    }
+
+    /// True one time in `n`.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.next_u64().is_multiple_of(n)
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+
+    pub fn letter(&mut self) -> char {
+        char::from(b'a' + self.range(0, 26) as u8)
+    }
+}
+
+fn lines<I: IntoIterator<Item = String>>(lines: I) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+/// Frequency changes whose running total drifts a little each time through
+/// the list, so it always comes back to a frequency it's seen.
+pub fn day01(rng: &mut Rng) -> String {
+    let mut changes = (0..999)
+        .map(|_| if rng.one_in(2) { rng.range(1, 20) } else { -rng.range(1, 20) })
+        .collect::<Vec<i64>>();
+
+    let drift = *rng.pick(&[-3, -2, -1, 1, 2, 3]);
+    let last = drift - changes.iter().sum::<i64>();
+    if last != 0 {
+        changes.push(last);
+    }
+    rng.shuffle(&mut changes);
+
+    lines(changes.iter().map(|c| format!("{:+}", c)))
+}
+
+/// Box IDs where exactly one pair differs by a single letter.
+pub fn day02(rng: &mut Rng) -> String {
+    let near = |a: &str, b: &str| a.chars().zip(b.chars()).filter(|(a, b)| a != b).count() <= 1;
+
+    let mut ids: Vec<String> = Vec::new();
+    while ids.len() < 249 {
+        let id = (0..26).map(|_| rng.letter()).collect::<String>();
+        if !ids.iter().any(|other| near(other, &id)) {
+            ids.push(id);
+        }
+    }
+
+    let mut twin = rng.pick(&ids).chars().collect::<Vec<char>>();
+    let i = rng.index(twin.len());
+    twin[i] = loop {
+        let c = rng.letter();
+        if c != twin[i] {
+            break c;
+        }
+    };
+    ids.insert(rng.index(ids.len()), twin.into_iter().collect());
+
+    lines(ids)
+}
+
+/// Claims on a thousand-inch square of fabric, each overlapping another
+/// except for one.
+pub fn day03(rng: &mut Rng) -> String {
+    const FABRIC: i64 = 1000;
+
+    let claim = |rng: &mut Rng, origin: Bounds| {
+        let x = rng.range(origin.min.x, origin.max.x + 1);
+        let y = rng.range(origin.min.y, origin.max.y + 1);
+        let w = rng.range(5, 30).min(FABRIC - x);
+        let h = rng.range(5, 30).min(FABRIC - y);
+        Bounds::new(Point::new(x, y), Point::new(x + w - 1, y + h - 1))
+    };
+    let fabric = Bounds::new(Point::new(0, 0), Point::new(FABRIC - 1, FABRIC - 1));
+
+    let intact = claim(rng, fabric);
+    let mut claims = Vec::new();
+    while claims.len() < 1300 {
+        // Every claim after the first starts inside an earlier one, and the
+        // second inside the first, so they all overlap something
+        let origin = match claims.len() {
+            0 => fabric,
+            n => claims[rng.index(n)],
+        };
+        let rect = claim(rng, origin);
+        if rect.intersection(&intact).is_none() {
+            claims.push(rect);
+        }
+    }
+
+    claims.insert(rng.index(claims.len()), intact);
+
+    lines(claims.iter().enumerate().map(|(i, rect)| {
+        format!("#{} @ {},{}: {}x{}", i + 1, rect.min.x, rect.min.y, rect.width(), rect.height())
+    }))
+}
+
+/// A few months of guard shifts, out of order like the real log.
+pub fn day04(rng: &mut Rng) -> String {
+    let guards = (0..20).map(|_| rng.range(10, 3500) as usize).collect::<Vec<usize>>();
+    let first = NaiveDate::from_ymd_opt(1518, 2, 1).expect("a valid date");
+
+    let mut records = Vec::new();
+    for day in 0..120 {
+        let date = first + Duration::days(day);
+        let at = |date: NaiveDate, hour, minute| date.and_hms_opt(hour, minute, 0).expect("a valid time");
+
+        let begins = if rng.one_in(2) {
+            at(date - Duration::days(1), 23, rng.range(45, 60) as u32)
+        } else {
+            at(date, 0, rng.range(0, 5) as u32)
+        };
+        records.push(Record { time: begins, event: Event::BeginsShift(*rng.pick(&guards)) });
+
+        // At least one nap on the first night, so somebody sleeps
+        let naps = rng.range(if day == 0 { 1 } else { 0 }, 4) as usize;
+        let mut minutes = BTreeSet::new();
+        while minutes.len() < naps * 2 {
+            minutes.insert(rng.range(5, 60) as u32);
+        }
+        for (i, minute) in minutes.into_iter().enumerate() {
+            let event = if i % 2 == 0 { Event::FallsAsleep } else { Event::WakesUp };
+            records.push(Record { time: at(date, 0, minute), event });
+        }
+    }
+
+    rng.shuffle(&mut records);
+    lines(records.iter().map(Record::to_string))
+}
+
+/// A long polymer, with some units placed to react with their neighbours.
+pub fn day05(rng: &mut Rng) -> String {
+    let mut polymer = String::with_capacity(50_000);
+
+    while polymer.len() < 50_000 {
+        let unit = rng.letter();
+        if rng.one_in(3) {
+            polymer.push(unit);
+            polymer.push(unit.to_ascii_uppercase());
+        } else if rng.one_in(2) {
+            polymer.push(unit.to_ascii_uppercase());
+        } else {
+            polymer.push(unit);
+        }
+    }
+
+    polymer + "\n"
+}
+
+/// Fifty different coordinates, spread out like the real ones.
+pub fn day06(rng: &mut Rng) -> String {
+    let mut coords = BTreeSet::new();
+    let mut order = Vec::new();
+
+    while order.len() < 50 {
+        let coord = (rng.range(40, 360), rng.range(40, 360));
+        if coords.insert(coord) {
+            order.push(coord);
+        }
+    }
+
+    lines(order.iter().map(|(x, y)| format!("{}, {}", x, y)))
+}
+
+/// Instructions for all 26 steps, with no cycles, where every step is in at
+/// least one instruction.
+pub fn day07(rng: &mut Rng) -> String {
+    let mut steps = ('A'..='Z').collect::<Vec<char>>();
+    rng.shuffle(&mut steps);
+
+    let mut instructions = BTreeSet::new();
+    for (i, &step) in steps.iter().enumerate().skip(1) {
+        let prereqs = rng.range(1, 4);
+        for _ in 0..prereqs {
+            instructions.insert((steps[rng.index(i)], step));
+        }
+    }
+
+    let mut instructions = instructions.into_iter().collect::<Vec<(char, char)>>();
+    rng.shuffle(&mut instructions);
+
+    lines(instructions.iter().map(|(prereq, step)| {
+        format!("Step {} must be finished before step {} can begin.", prereq, step)
+    }))
+}
+
+/// Stars that spell out a blotchy message some ten thousand seconds in.
+pub fn day10(rng: &mut Rng) -> String {
+    const SPEED: i64 = 5;
+
+    let seconds = rng.range(10_000, 11_000);
+    let width = rng.range(4, 8) * 8 - 2;
+    let height = 10;
+
+    let mut stars = Vec::new();
+    for p in Bounds::new(Point::new(0, 0), Point::new(width - 1, height - 1)).points() {
+        if rng.one_in(3) {
+            stars.push((p, Point::new(rng.range(-SPEED, SPEED + 1), rng.range(-SPEED, SPEED + 1))));
+        }
+    }
+
+    // Stars on each edge of the message moving out both ways at full speed,
+    // so the message is smallest at exactly `seconds` and no other time
+    for &vx in &[-SPEED, SPEED] {
+        for &x in &[0, width - 1] {
+            stars.push((Point::new(x, rng.range(0, height)), Point::new(vx, 0)));
+        }
+    }
+    for &vy in &[-SPEED, SPEED] {
+        for &y in &[0, height - 1] {
+            stars.push((Point::new(rng.range(0, width), y), Point::new(0, vy)));
+        }
+    }
+    rng.shuffle(&mut stars);
+
+    lines(stars.iter().map(|&(message, velocity)| {
+        let position = Point::new(message.x - velocity.x * seconds, message.y - velocity.y * seconds);
+        day10::Point { position, velocity }.to_string()
+    }))
+}
+
+/// A row of a hundred pots and a rule for every neighbourhood. Empty pots
+/// always stay empty, or the row would grow plants forever.
+pub fn day12(rng: &mut Rng) -> String {
+    let pot = |rng: &mut Rng| if rng.one_in(2) { '#' } else { '.' };
+
+    let state = (0..100).map(|_| pot(rng)).collect::<String>();
+
+    let mut rules = (0..32u32)
+        .map(|n| {
+            let config = (0..5).rev()
+                .map(|bit| if n & (1 << bit) != 0 { '#' } else { '.' })
+                .collect::<String>();
+            let result = if n == 0 { '.' } else { pot(rng) };
+            format!("{} => {}", config, result)
+        })
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut rules);
+
+    format!("initial state: {}\n\n{}", state, lines(rules))
+}
+
+/// Rectangular loops of track, crossing at intersections. Carts come in
+/// pairs heading for each other on a straight, plus one more, so they crash
+/// until exactly one is left.
+pub fn day13(rng: &mut Rng) -> String {
+    let (width, height) = (rng.range(40, 80), rng.range(40, 80));
+    let mut map = Grid::new(Bounds::new(Point::new(0, 0), Point::new(width - 1, height - 1)), ' ');
+
+    let mut loops = Vec::new();
+    for _ in 0..200 {
+        if loops.len() == 8 {
+            break;
+        }
+
+        let (x, y) = (rng.range(0, width - 4), rng.range(0, height - 4));
+        let max = Point::new(rng.range(x + 3, width), rng.range(y + 3, height));
+        let rect = Bounds::new(Point::new(x, y), max);
+        if let Some(track) = track(&map, rect) {
+            for (point, c) in track {
+                map[point] = c;
+            }
+            loops.push(rect);
+        }
+    }
+
+    let mut carts = BTreeMap::new();
+    let straight = |map: &Grid<char>, carts: &BTreeMap<Point, char>, p: Point| {
+        matches!(map[p], '-' | '|') && !carts.contains_key(&p)
+    };
+
+    // The odd one out goes first. No pair has a cart between them, and
+    // there's no intersection between them either, so they always meet.
+    loop {
+        let p = Point::new(rng.range(0, width), rng.range(0, height));
+        if straight(&map, &carts, p) {
+            let cart = if map[p] == '-' { *rng.pick(&['<', '>']) } else { *rng.pick(&['^', 'v']) };
+            carts.insert(p, cart);
+            break;
+        }
+    }
+
+    let pairs = rng.range(2, 6);
+    for _ in 0..200 {
+        if carts.len() as i64 == pairs * 2 + 1 {
+            break;
+        }
+
+        let rect = *rng.pick(&loops);
+        let (a, b) = if rng.one_in(2) {
+            let y = if rng.one_in(2) { rect.min.y } else { rect.max.y };
+            let x = rng.range(rect.min.x + 1, rect.max.x);
+            (Point::new(x, y), Point::new(rng.range(x + 1, rect.max.x + 1), y))
+        } else {
+            let x = if rng.one_in(2) { rect.min.x } else { rect.max.x };
+            let y = rng.range(rect.min.y + 1, rect.max.y);
+            (Point::new(x, y), Point::new(x, rng.range(y + 1, rect.max.y + 1)))
+        };
+
+        if Bounds::new(a, b).points().all(|p| straight(&map, &carts, p)) {
+            let (ca, cb) = if map[a] == '-' { ('>', '<') } else { ('v', '^') };
+            carts.insert(a, ca);
+            carts.insert(b, cb);
+        }
+    }
+
+    for (p, cart) in carts {
+        map[p] = cart;
+    }
+
+    map.to_string()
+}
+
+/// The track for a loop around `rect`, or `None` if it would run along or
+/// turn on another loop. Crossing one makes an intersection.
+fn track(map: &Grid<char>, rect: Bounds) -> Option<Vec<(Point, char)>> {
+    let Bounds { min, max } = rect;
+
+    rect.points()
+        .filter(|&p| rect.on_edge(p))
+        .map(|p| {
+            let corner = (p.x == min.x || p.x == max.x) && (p.y == min.y || p.y == max.y);
+            let horizontal = p.y == min.y || p.y == max.y;
+
+            let c = match (corner, horizontal, map[p]) {
+                (true, _, ' ') => if (p.x == min.x) == (p.y == min.y) { '/' } else { '\\' },
+                (false, true, ' ') => '-',
+                (false, false, ' ') => '|',
+                (false, true, '|') | (false, false, '-') => '+',
+                _ => return None,
+            };
+            Some((p, c))
+        })
+        .collect()
+}
+
+/// A walled cave with rocks scattered about, all of it reachable, and a few
+/// elves and goblins.
+pub fn day15(rng: &mut Rng) -> String {
+    let size = rng.range(12, 24);
+    let bounds = Bounds::new(Point::new(0, 0), Point::new(size - 1, size - 1));
+    let cave = Grid::from_fn(bounds, |p| if bounds.on_edge(p) || rng.one_in(5) { '#' } else { '.' });
+
+    // Wall off everything that can't be reached from the biggest open space
+    let mut unreached = cave.iter()
+        .filter(|(_, &c)| c == '.')
+        .map(|(p, _)| p)
+        .collect::<BTreeSet<Point>>();
+    let mut biggest = BTreeSet::new();
+    while let Some(&start) = unreached.iter().next() {
+        let search = path::bfs(&path::walkable(&cave, |&c| c == '.'), start);
+        let space = search.reached().map(|(p, _)| p).collect::<BTreeSet<Point>>();
+        unreached = &unreached - &space;
+        if space.len() > biggest.len() {
+            biggest = space;
+        }
+    }
+    let mut cave = Grid::from_fn(bounds, |p| if biggest.contains(&p) { '.' } else { '#' });
+
+    let mut open = biggest.into_iter().collect::<Vec<Point>>();
+    rng.shuffle(&mut open);
+    let elves = rng.range(2, 7) as usize;
+    let goblins = rng.range(2, 9) as usize;
+    for (i, &p) in open.iter().take(elves + goblins).enumerate() {
+        cave[p] = if i < elves { 'E' } else { 'G' };
+    }
+
+    cave.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn inputs_are_valid() {
+        for &day in DAYS {
+            for seed in 0..3 {
+                let text = input(day, seed).unwrap();
+                let errors = (registry::get(day).unwrap().check)(&text);
+                assert!(errors.is_empty(), "day {} seed {}: {:?}", day, seed, errors);
+            }
+        }
+
+        assert_eq!(input(1, 7), input(1, 7));
+        assert_ne!(input(1, 7), input(1, 8));
+        assert_eq!(input(9, 7), None);
+    }
+
+    #[test]
+    fn inputs_have_answers() -> crate::error::Result<()> {
+        for seed in 0..3 {
+            let claims = crate::day03::parse(&input(3, seed).unwrap())?;
+            assert_ne!(crate::day03::part2(&claims), 0);
+
+            let ids = crate::day02::parse(&input(2, seed).unwrap())?;
+            assert_eq!(crate::day02::part2(&ids).len(), 25);
+
+            // Crashes until there's one cart left, rather than running forever
+            let map = crate::day13::parse(&input(13, seed).unwrap())?;
+            crate::day13::part2(&map);
+        }
+        Ok(())
+    }
+}
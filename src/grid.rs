@@ -0,0 +1,311 @@
+//! A dense 2D grid, and the points and bounds that index it, for the days
+//! that work on maps.
+
+use crate::error::{Error, Result};
+
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Sub};
+
+/// A location, or an offset between two. `y` comes first so points sort in
+/// reading order: top to bottom, then left to right.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Point {
+    pub y: i64,
+    pub x: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The four points sharing an edge with this one, in reading order.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        [(0, -1), (-1, 0), (1, 0), (0, 1)].iter()
+            .map(move |&(x, y)| self + Point { x, y })
+    }
+
+    /// The eight points around this one, in reading order.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)].iter()
+            .map(move |&(x, y)| self + Point { x, y })
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// The most points a grid sized by a puzzle input may cover. Real inputs
+/// need a few million at most; an input asking for more gets an error
+/// instead of all the memory.
+pub const MAX_AREA: i64 = 1 << 24;
+
+/// The smallest rectangle holding a set of points. Both corners are inside it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn new(min: Point, max: Point) -> Bounds {
+        Bounds { min, max }
+    }
+
+    /// The bounds of `points`, or `None` if there aren't any.
+    pub fn of<I: IntoIterator<Item = Point>>(points: I) -> Option<Bounds> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(Bounds::new(first, first), |b, p| Bounds {
+            min: Point { x: b.min.x.min(p.x), y: b.min.y.min(p.y) },
+            max: Point { x: b.max.x.max(p.x), y: b.max.y.max(p.y) },
+        }))
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x.saturating_sub(self.min.x).saturating_add(1)
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y.saturating_sub(self.min.y).saturating_add(1)
+    }
+
+    /// How many points are inside, or `i64::MAX` if that's more than fits.
+    pub fn area(&self) -> i64 {
+        self.width().max(0).saturating_mul(self.height().max(0))
+    }
+
+    /// These bounds, if a grid over them is small enough for `day` to build.
+    pub fn limit(self, day: u8) -> Result<Bounds> {
+        if self.area() > MAX_AREA {
+            return Err(Error::invalid(day, format!(
+                "a {}x{} grid is too big, the most is {} points", self.width(), self.height(), MAX_AREA
+            )));
+        }
+
+        Ok(self)
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x &&
+        p.y >= self.min.y && p.y <= self.max.y
+    }
+
+    /// Whether `p` is on the outermost ring of the bounds.
+    pub fn on_edge(&self, p: Point) -> bool {
+        self.contains(p) && (
+            p.x == self.min.x || p.x == self.max.x ||
+            p.y == self.min.y || p.y == self.max.y
+        )
+    }
+
+    /// The overlap of two bounds, if they have one.
+    pub fn intersection(&self, other: &Bounds) -> Option<Bounds> {
+        let min = Point { x: self.min.x.max(other.min.x), y: self.min.y.max(other.min.y) };
+        let max = Point { x: self.max.x.min(other.max.x), y: self.max.y.min(other.max.y) };
+
+        if min.x <= max.x && min.y <= max.y {
+            Some(Bounds { min, max })
+        } else {
+            None
+        }
+    }
+
+    /// Every point inside, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Bounds { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point { x, y }))
+    }
+}
+
+/// A rectangle of `T`s, stored row by row. Its points needn't start at 0,0.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    bounds: Bounds,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(bounds: Bounds, fill: T) -> Grid<T> {
+        Grid { bounds, cells: vec![fill; bounds.area() as usize] }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn<F: FnMut(Point) -> T>(bounds: Bounds, f: F) -> Grid<T> {
+        Grid { bounds, cells: bounds.points().map(f).collect() }
+    }
+
+    /// Reads a map of characters, one row per line, with the top left at
+    /// 0,0. Short lines are padded with spaces.
+    pub fn from_chars<F>(input: &str, mut f: F) -> Result<Grid<T>>
+    where F: FnMut(Point, char) -> Result<T> {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for (y, line) in lines.iter().enumerate() {
+            let padded = line.chars().chain(std::iter::repeat(' ')).take(width);
+            for (x, c) in padded.enumerate() {
+                cells.push(f(Point::new(x as i64, y as i64), c)?);
+            }
+        }
+
+        let max = Point::new(width as i64 - 1, lines.len() as i64 - 1);
+        Ok(Grid { bounds: Bounds::new(Point::default(), max), cells })
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds.width() as usize
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds.height() as usize
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.bounds.contains(p)
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        if self.contains(p) {
+            let offset = p - self.bounds.min;
+            Some(offset.y as usize * self.width() + offset.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(move |i| &mut self.cells[i])
+    }
+
+    /// Every point and its cell, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.bounds.points().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width().max(1))
+    }
+
+    /// The neighbours of `p` that are on the grid, in reading order.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbours4().filter(move |&n| self.contains(n))
+    }
+
+    /// The points around `p` that are on the grid, in reading order.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbours8().filter(move |&n| self.contains(n))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p).unwrap_or_else(|| panic!("{} is off the grid", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p).unwrap_or_else(|| panic!("{} is off the grid", p))
+    }
+}
+
+/// Draws one character per cell, with a newline after every row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbours() {
+        let p = Point::new(1, 1);
+        let around: Vec<Point> = p.neighbours8().collect();
+        let mut sorted = around.clone();
+        sorted.sort();
+        assert_eq!(around, sorted);
+        assert_eq!(around.len(), 8);
+
+        let grid = Grid::new(Bounds::new(Point::new(0, 0), Point::new(2, 2)), 0);
+        let corner: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+    }
+
+    #[test]
+    fn chars_round_trip() -> Result<()> {
+        let map = "#.#\n..\n";
+        let grid = Grid::from_chars(map, |_, c| Ok(c))?;
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 0)], '#');
+        assert_eq!(grid.to_string(), "#.#\n.. \n");
+        Ok(())
+    }
+
+    #[test]
+    fn offset_bounds() {
+        let bounds = Bounds::of(vec![Point::new(-2, 3), Point::new(1, 5)]).unwrap();
+        assert_eq!((bounds.width(), bounds.height()), (4, 3));
+
+        let mut grid = Grid::new(bounds, 0);
+        grid[Point::new(-2, 3)] = 1;
+        assert_eq!(grid.values().next(), Some(&1));
+        assert_eq!(grid.get(Point::new(0, 0)), None);
+        assert!(bounds.on_edge(Point::new(1, 4)));
+        assert!(!bounds.on_edge(Point::new(0, 4)));
+    }
+}
@@ -0,0 +1,79 @@
+//! Finds a day's puzzle input on disk.
+//!
+//! Inputs live in `input/`, or wherever `AOC_INPUT_DIR` points, as
+//! `dayNN.txt`. Examples sit beside them as `dayNN_example.txt`. Days whose
+//! input is a single number, like day 11's serial, keep it in a file too.
+
+use crate::error::Result;
+
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io::{stdin, BufRead, BufReader, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+
+/// The environment variable that overrides the input directory.
+pub const DIR_VAR: &str = "AOC_INPUT_DIR";
+
+pub fn dir() -> PathBuf {
+    env::var_os(DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("input"))
+}
+
+/// The file name for a day's real input, or for its example.
+pub fn file_name(day: u8, example: bool) -> String {
+    if example {
+        format!("day{:02}_example.txt", day)
+    } else {
+        format!("day{:02}.txt", day)
+    }
+}
+
+pub fn path(day: u8, example: bool) -> PathBuf {
+    dir().join(file_name(day, example))
+}
+
+/// Finds `file` as given, or else in the input directory, so
+/// `example1.txt` works from anywhere.
+pub fn resolve(file: &str) -> PathBuf {
+    let path = Path::new(file);
+    let in_dir = dir().join(file);
+
+    if !path.exists() && in_dir.exists() {
+        in_dir
+    } else {
+        path.to_path_buf()
+    }
+}
+
+pub fn load(day: u8, example: bool) -> Result<String> {
+    Ok(fs::read_to_string(path(day, example))?)
+}
+
+/// Reads piped input, or the day's input file when nothing is piped in.
+pub fn read(day: u8) -> Result<String> {
+    if stdin().is_terminal() {
+        load(day, false)
+    } else {
+        let mut input = String::new();
+        stdin().read_to_string(&mut input)?;
+        Ok(input)
+    }
+}
+
+/// Like [`read`], but a line at a time, for input too big to hold at once
+/// or still being written.
+pub fn reader(day: u8) -> Result<Box<dyn BufRead>> {
+    if stdin().is_terminal() {
+        Ok(Box::new(BufReader::new(File::open(path(day, false))?)))
+    } else {
+        Ok(Box::new(stdin().lock()))
+    }
+}
+
+#[test]
+fn file_names() {
+    assert_eq!(file_name(3, false), "day03.txt");
+    assert_eq!(file_name(12, true), "day12_example.txt");
+}
@@ -0,0 +1,54 @@
+//! Solutions to [Advent of Code 2018](https://adventofcode.com/2018), one
+//! module per day, plus the [`registry`] the `aoc` runner dispatches through.
+//!
+//! Every day module has a `parse` function turning the puzzle input into the
+//! day's domain types, and `part1`/`part2` functions solving from those. Each
+//! day also implements [`solution::Solution`] so the runner, tests and
+//! benchmarks can treat all days the same way.
+//!
+//! Expected answers live in `answers/dayNN.toml`; [`verify`] checks every day
+//! against them.
+
+pub mod answers;
+pub mod batch;
+pub mod budget;
+pub mod device;
+pub mod error;
+pub mod fuzz;
+pub mod gen;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod path;
+pub mod reference;
+pub mod registry;
+pub mod solution;
+pub mod verify;
+pub mod visualize;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
@@ -0,0 +1,102 @@
+//! How the runner prints results: as text for people, or as JSON, one object
+//! per line, for tools.
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format `{}`; use `text` or `json`", s)),
+        }
+    }
+}
+
+/// A JSON object, built one field at a time.
+#[derive(Debug, Clone, Default)]
+pub struct Object(Vec<String>);
+
+impl Object {
+    pub fn new() -> Object {
+        Object::default()
+    }
+
+    pub fn number<N: fmt::Display>(mut self, key: &str, value: N) -> Object {
+        self.0.push(format!("{}:{}", string(key), value));
+        self
+    }
+
+    pub fn string(mut self, key: &str, value: &str) -> Object {
+        self.0.push(format!("{}:{}", string(key), string(value)));
+        self
+    }
+
+    /// A string, or `null` when there isn't one.
+    pub fn maybe(self, key: &str, value: Option<&str>) -> Object {
+        match value {
+            Some(value) => self.string(key, value),
+            None => {
+                let mut object = self;
+                object.0.push(format!("{}:null", string(key)));
+                object
+            },
+        }
+    }
+
+    /// Milliseconds, to the microsecond.
+    pub fn elapsed(self, key: &str, elapsed: Duration) -> Object {
+        self.number(key, format!("{:.3}", elapsed.as_secs_f64() * 1000.0))
+    }
+}
+
+impl fmt::Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{{}}}", self.0.join(","))
+    }
+}
+
+/// Quotes and escapes a JSON string.
+pub fn string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+#[test]
+fn json_object() {
+    let object = Object::new()
+        .number("day", 10)
+        .string("answer", "# #\n\"#\"\t\u{1}")
+        .elapsed("elapsed_ms", Duration::from_micros(1500))
+        .maybe("expected", None);
+
+    assert_eq!(
+        object.to_string(),
+        r##"{"day":10,"answer":"# #\n\"#\"\t\u0001","elapsed_ms":1.500,"expected":null}"##
+    );
+}
@@ -0,0 +1,96 @@
+//! Small helpers shared by the days' parsers.
+
+use crate::error::{Error, Result};
+
+use std::str::FromStr;
+
+/// Parses every non-blank line of `input`, stamping errors with their line
+/// number.
+pub fn lines<T, F>(input: &str, mut parse: F) -> Result<Vec<T>>
+where F: FnMut(&str) -> Result<T> {
+    input.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse(line).map_err(|e| e.at_line(index + 1)))
+        .collect()
+}
+
+/// Like [`lines`], but keeps going past bad lines and returns every error.
+pub fn check_lines<T, F>(input: &str, mut parse: F) -> Vec<Error>
+where F: FnMut(&str) -> Result<T> {
+    input.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(index, line)| parse(line).err().map(|e| e.at_line(index + 1)))
+        .collect()
+}
+
+/// Splits `line` on whitespace, pairing each field with its column (from 1).
+pub fn fields(line: &str) -> Vec<(usize, &str)> {
+    let mut fields = Vec::new();
+    let mut start = None;
+
+    for (column, (index, c)) in line.char_indices().enumerate() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some((column + 1, index)),
+            (true, Some((col, from))) => {
+                fields.push((col, &line[from..index]));
+                start = None;
+            },
+            _ => (),
+        }
+    }
+
+    if let Some((col, from)) = start {
+        fields.push((col, &line[from..]));
+    }
+
+    fields
+}
+
+/// Parses `"a<sep>b"` into two values, like `"3,2"` or `"5x4"`.
+pub fn pair<T: FromStr>(s: &str, sep: char) -> Option<(T, T)> {
+    let mut split = s.splitn(2, sep);
+    let a = split.next()?.trim().parse().ok()?;
+    let b = split.next()?.trim().parse().ok()?;
+
+    Some((a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_fields() {
+        assert_eq!(
+            fields("#1 @  1,3: 4x4"),
+            vec![(1, "#1"), (4, "@"), (7, "1,3:"), (12, "4x4")]
+        );
+        assert!(fields("   ").is_empty());
+    }
+
+    #[test]
+    fn check_every_line() {
+        let number = |line: &str| line.trim().parse::<u8>()
+            .map_err(|_| Error::parse(1, 1, line, "a number"));
+
+        let errors = check_lines("1\nx\n\n3\ny\n", number);
+        let lines = errors.iter()
+            .map(|e| match e {
+                Error::Parse { line, .. } => *line,
+                _ => 0,
+            })
+            .collect::<Vec<usize>>();
+        assert_eq!(lines, vec![2, 5]);
+
+        assert!(check_lines("1\n2\n", number).is_empty());
+    }
+
+    #[test]
+    fn pairs() {
+        assert_eq!(pair::<usize>("3,2", ','), Some((3, 2)));
+        assert_eq!(pair::<i32>(" -1, 4", ','), Some((-1, 4)));
+        assert_eq!(pair::<usize>("5x", 'x'), None);
+    }
+}
@@ -0,0 +1,232 @@
+//! Shortest paths over grids and graphs: breadth-first search, Dijkstra and
+//! A*.
+//!
+//! Ties are always broken by the nodes' `Ord`, which for
+//! [`Point`](crate::grid::Point) is reading order. Of two equally short paths
+//! the one whose first step comes first wins, as day 15's units expect.
+
+use crate::grid::{Grid, Point};
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Anything with nodes joined by weighted edges.
+pub trait Graph {
+    type Node: Copy + Eq + Ord + Hash;
+
+    /// The nodes reachable in one step from `node`, and what each step costs.
+    fn neighbours(&self, node: Self::Node) -> Vec<(Self::Node, u64)>;
+}
+
+/// An adjacency list.
+impl<N: Copy + Eq + Ord + Hash> Graph for BTreeMap<N, Vec<(N, u64)>> {
+    type Node = N;
+
+    fn neighbours(&self, node: N) -> Vec<(N, u64)> {
+        self.get(&node).cloned().unwrap_or_default()
+    }
+}
+
+/// The cells of a grid that can be walked on, moving up, down, left or right.
+pub struct Walkable<'a, T, F> {
+    grid: &'a Grid<T>,
+    open: F,
+}
+
+pub fn walkable<T, F: Fn(&T) -> bool>(grid: &Grid<T>, open: F) -> Walkable<'_, T, F> {
+    Walkable { grid, open }
+}
+
+impl<T, F: Fn(&T) -> bool> Graph for Walkable<'_, T, F> {
+    type Node = Point;
+
+    fn neighbours(&self, node: Point) -> Vec<(Point, u64)> {
+        self.grid.neighbours4(node)
+            .filter(|&n| (self.open)(&self.grid[n]))
+            .map(|n| (n, 1))
+            .collect()
+    }
+}
+
+/// Everything a search found from its start node.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    pub start: N,
+    distance: HashMap<N, u64>,
+    parent: HashMap<N, N>,
+    first: HashMap<N, N>,
+}
+
+impl<N: Copy + Eq + Ord + Hash> Search<N> {
+    fn new(start: N) -> Search<N> {
+        let mut distance = HashMap::new();
+        distance.insert(start, 0);
+        Search { start, distance, parent: HashMap::new(), first: HashMap::new() }
+    }
+
+    /// Records reaching `next` from `node` at `distance`, if that's shorter,
+    /// or as short with an earlier first step.
+    fn relax(&mut self, node: N, next: N, distance: u64) -> bool {
+        let first = if node == self.start { next } else { self.first[&node] };
+        let better = match self.distance.get(&next) {
+            None => true,
+            Some(&d) if distance < d => true,
+            Some(&d) => distance == d && self.first.get(&next).is_some_and(|&f| first < f),
+        };
+
+        if better {
+            self.distance.insert(next, distance);
+            self.parent.insert(next, node);
+            self.first.insert(next, first);
+        }
+
+        better
+    }
+
+    pub fn distance(&self, node: N) -> Option<u64> {
+        self.distance.get(&node).copied()
+    }
+
+    pub fn reached(&self) -> impl Iterator<Item = (N, u64)> + '_ {
+        self.distance.iter().map(|(&n, &d)| (n, d))
+    }
+
+    /// The nodes from the start to `node`, both included.
+    pub fn path(&self, node: N) -> Option<Vec<N>> {
+        self.distance(node)?;
+
+        let mut path = vec![node];
+        let mut node = node;
+        while let Some(&parent) = self.parent.get(&node) {
+            path.push(parent);
+            node = parent;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// The step to take from the start to head for `node`.
+    pub fn first_step(&self, node: N) -> Option<N> {
+        self.first.get(&node).copied()
+    }
+
+    /// The closest node matching `target`, first in order among equals.
+    pub fn nearest<F: Fn(N) -> bool>(&self, target: F) -> Option<(N, u64)> {
+        self.reached()
+            .filter(|&(n, _)| target(n))
+            .min_by_key(|&(n, d)| (d, n))
+    }
+}
+
+/// Counts steps, ignoring the edges' costs.
+pub fn bfs<G: Graph>(graph: &G, start: G::Node) -> Search<G::Node> {
+    let mut search = Search::new(start);
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = search.distance[&node] + 1;
+        let mut neighbours = graph.neighbours(node);
+        neighbours.sort();
+
+        for (next, _) in neighbours {
+            let seen = search.distance.contains_key(&next);
+            if search.relax(node, next, distance) && !seen {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+pub fn dijkstra<G: Graph>(graph: &G, start: G::Node) -> Search<G::Node> {
+    let mut search = Search::new(start);
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((0, start)));
+
+    while let Some(Reverse((distance, node))) = heap.pop() {
+        if distance > search.distance[&node] {
+            continue;
+        }
+
+        for (next, cost) in graph.neighbours(node) {
+            if search.relax(node, next, distance + cost) {
+                heap.push(Reverse((distance + cost, next)));
+            }
+        }
+    }
+
+    search
+}
+
+/// The cost and path from `start` to `goal`. `estimate` must never guess
+/// more than the real remaining cost.
+pub fn astar<G, H>(graph: &G, start: G::Node, goal: G::Node, estimate: H) -> Option<(u64, Vec<G::Node>)>
+where G: Graph, H: Fn(G::Node) -> u64 {
+    let mut search = Search::new(start);
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((estimate(start), 0, start)));
+
+    while let Some(Reverse((_, distance, node))) = heap.pop() {
+        if node == goal {
+            return search.path(goal).map(|path| (distance, path));
+        }
+        if distance > search.distance[&node] {
+            continue;
+        }
+
+        for (next, cost) in graph.neighbours(node) {
+            let distance = distance + cost;
+            if search.relax(node, next, distance) {
+                heap.push(Reverse((distance + estimate(next), distance, next)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Result;
+
+    // The movement example from day 15: the elf at 2,1 heads for 4,2
+    const MAZE: &str = "\
+#######
+#.E...#
+#.....#
+#...G.#
+#######";
+
+    #[test]
+    fn reading_order() -> Result<()> {
+        let grid = Grid::from_chars(MAZE, |_, c| Ok(c))?;
+        let search = bfs(&walkable(&grid, |&c| c == '.'), Point::new(2, 1));
+
+        let in_range = |p: Point| grid.neighbours4(p).any(|n| grid[n] == 'G');
+        let (target, distance) = search.nearest(|p| grid[p] == '.' && in_range(p)).unwrap();
+        assert_eq!((target, distance), (Point::new(4, 2), 3));
+        assert_eq!(search.first_step(target), Some(Point::new(3, 1)));
+        assert_eq!(search.path(target).unwrap().len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn weighted() {
+        let mut graph = BTreeMap::new();
+        graph.insert('a', vec![('b', 7), ('c', 2)]);
+        graph.insert('c', vec![('b', 3), ('d', 8)]);
+        graph.insert('b', vec![('d', 1)]);
+
+        let search = dijkstra(&graph, 'a');
+        assert_eq!(search.distance('d'), Some(6));
+        assert_eq!(search.path('d'), Some(vec!['a', 'c', 'b', 'd']));
+        assert_eq!(search.distance('e'), None);
+
+        assert_eq!(astar(&graph, 'a', 'd', |_| 0), Some((6, vec!['a', 'c', 'b', 'd'])));
+    }
+}
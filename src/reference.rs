@@ -0,0 +1,273 @@
+//! Deliberately simple versions of some days, to check the real solvers
+//! against.
+//!
+//! A day with a `reference` module implements [`Reference`] and goes in
+//! [`DAYS`]. [`compare`] runs both versions of a part over an input and, if
+//! they disagree, shrinks the input down to as little as still shows it, for
+//! `aoc diff` and the tests.
+
+use crate::*;
+use crate::error::Error;
+use crate::registry::Part;
+use crate::solution::{Answer, Result, Solution};
+
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+/// The simple versions of a day's parts. `part2` is left unsolved where
+/// there isn't one.
+pub trait Reference: Solution {
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub part1: Part,
+    pub part2: Part,
+}
+
+impl Day {
+    pub const fn new<S: Reference>() -> Day {
+        Day {
+            day: S::DAY,
+            part1: part1::<S>,
+            part2: part2::<S>,
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<Part> {
+        match part {
+            1 => Some(self.part1),
+            2 => Some(self.part2),
+            _ => None,
+        }
+    }
+}
+
+fn part1<S: Reference>(input: &str) -> Result<Answer> {
+    Ok(<S as Reference>::part1(&S::parse(input)?))
+}
+
+fn part2<S: Reference>(input: &str) -> Result<Answer> {
+    Ok(<S as Reference>::part2(&S::parse(input)?))
+}
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+pub static DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day11::Day11>(),
+];
+
+/// How one version of a part did on an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(String),
+    Error(String),
+    Panicked(String),
+    /// Ran out of its budget, so it can't be compared
+    Timeout,
+    /// There's no reference for the part
+    Unsolved,
+}
+
+impl Outcome {
+    fn run(part: Part, input: &str, timeout: Duration) -> Outcome {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| budget::limit(timeout, || part(input))));
+
+        match result {
+            Ok(Ok(Answer::Unsolved)) => Outcome::Unsolved,
+            Ok(Ok(answer)) => Outcome::Answer(answer.to_string()),
+            Ok(Err(Error::Timeout { .. })) => Outcome::Timeout,
+            Ok(Err(e)) => Outcome::Error(e.to_string()),
+            Err(payload) => Outcome::Panicked(batch::panic_message(payload)),
+        }
+    }
+
+    /// Two errors agree whatever they say, but an error never agrees with
+    /// an answer, and a panic agrees with nothing.
+    fn differs(&self, other: &Outcome) -> bool {
+        match (self, other) {
+            (Outcome::Timeout, _) | (_, Outcome::Timeout) => false,
+            (Outcome::Unsolved, _) | (_, Outcome::Unsolved) => false,
+            (Outcome::Answer(a), Outcome::Answer(b)) => a != b,
+            (Outcome::Error(_), Outcome::Error(_)) => false,
+            _ => true,
+        }
+    }
+
+    fn kind(&self) -> u8 {
+        match self {
+            Outcome::Answer(_) => 0,
+            Outcome::Error(_) => 1,
+            Outcome::Panicked(_) => 2,
+            Outcome::Timeout => 3,
+            Outcome::Unsolved => 4,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{}", answer.trim_end()),
+            Outcome::Error(e) => write!(f, "error: {}", e),
+            Outcome::Panicked(message) => write!(f, "panicked: {}", message),
+            Outcome::Timeout => write!(f, "timed out"),
+            Outcome::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+/// The smallest input found where the two versions of a part disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub part: u8,
+    pub input: String,
+    pub solver: Outcome,
+    pub reference: Outcome,
+}
+
+/// Runs every part `day` has a reference for over `input`, returning the
+/// first disagreement, shrunk.
+pub fn differ(day: u8, input: &str, timeout: Duration) -> Option<Disagreement> {
+    let solver = registry::get(day)?;
+    let reference = get(day)?;
+
+    (1..=2).find_map(|part| {
+        let solve = solver.part(part)?;
+        compare(part, solve, reference.part(part)?, input, timeout)
+    })
+}
+
+/// Runs `solve` and `reference` over `input`. If they disagree, shrinks the
+/// input while they still disagree the same way.
+pub fn compare(part: u8, solve: Part, reference: Part, input: &str, timeout: Duration) -> Option<Disagreement> {
+    // The reference goes first, so there's no waiting on a slow solver for
+    // a part without one
+    let outcomes = |input: &str| {
+        let expected = Outcome::run(reference, input, timeout);
+        match expected {
+            Outcome::Unsolved => (Outcome::Unsolved, expected),
+            _ => (Outcome::run(solve, input, timeout), expected),
+        }
+    };
+
+    let (solver, expected) = outcomes(input);
+    if !solver.differs(&expected) {
+        return None;
+    }
+
+    let kind = (solver.kind(), expected.kind());
+    let input = shrink(input, |candidate| {
+        let (a, b) = outcomes(candidate);
+        a.differs(&b) && (a.kind(), b.kind()) == kind
+    });
+
+    let (solver, reference) = outcomes(&input);
+    Some(Disagreement { part, input, solver, reference })
+}
+
+/// Drops ever smaller runs of lines from `input`, or of characters for an
+/// input on one line, for as long as `fails` still holds.
+pub fn shrink<F: Fn(&str) -> bool>(input: &str, fails: F) -> String {
+    let lines = input.lines().filter(|l| !l.trim().is_empty()).count() > 1;
+    let mut units = if lines {
+        input.lines().map(|line| format!("{}\n", line)).collect::<Vec<String>>()
+    } else {
+        input.trim_end().chars().map(String::from).collect()
+    };
+
+    let mut chunk = units.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        let mut shrunk = false;
+
+        while start < units.len() {
+            let end = (start + chunk).min(units.len());
+            let candidate = units[..start].concat() + &units[end..].concat();
+
+            if !candidate.trim().is_empty() && fails(&candidate) {
+                units.drain(start..end);
+                shrunk = true;
+            } else {
+                start = end;
+            }
+        }
+
+        if !shrunk {
+            chunk /= 2;
+        }
+    }
+
+    units.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen;
+
+    use std::time::Instant;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    #[test]
+    fn shrinks_to_the_culprit() {
+        fn sum(input: &str) -> Result<Answer> {
+            Ok(day01::parse(input)?.iter().sum::<isize>().into())
+        }
+
+        fn sum_without_sevens(input: &str) -> Result<Answer> {
+            Ok(day01::parse(input)?.iter().filter(|&&c| c != 7).sum::<isize>().into())
+        }
+
+        let input = "+1\n-2\n+7\n+3\n+5\n";
+        let disagreement = compare(1, sum_without_sevens, sum, input, TIMEOUT).unwrap();
+        assert_eq!(disagreement.input, "+7\n");
+        assert_eq!(disagreement.solver, Outcome::Answer("0".to_string()));
+        assert_eq!(disagreement.reference, Outcome::Answer("7".to_string()));
+
+        assert_eq!(compare(1, sum, sum, input, TIMEOUT), None);
+    }
+
+    #[test]
+    fn references_agree() {
+        for &day in &[2, 3, 5] {
+            for seed in 0..2 {
+                let input = gen::input(day, seed).unwrap();
+                assert_eq!(differ(day, &input, TIMEOUT), None, "day {} seed {}", day, seed);
+            }
+        }
+
+        assert_eq!(differ(1, "+1\n-2\n+3\n+1\n", TIMEOUT), None);
+        assert_eq!(differ(11, "42", TIMEOUT), None);
+    }
+
+    #[test]
+    fn huge_inputs_stop() {
+        let started = Instant::now();
+        assert_eq!(differ(3, "#1 @ 0,0: 400000x400000\n", Duration::from_secs(2)), None);
+        assert_eq!(differ(3, "#1 @ 0,0: 4000x4000\n#2 @ 1,1: 1x1\n", Duration::from_millis(1)), None);
+        assert!(started.elapsed() < Duration::from_secs(10), "took {:?}", started.elapsed());
+    }
+
+    #[test]
+    #[ignore = "day 6 part 2 counts areas instead of points, and day 7 doesn't take steps alphabetically"]
+    fn known_disagreements() {
+        assert_eq!(differ(6, &gen::input(6, 0).unwrap(), TIMEOUT), None);
+        assert_eq!(differ(7, &gen::input(7, 0).unwrap(), TIMEOUT), None);
+    }
+}
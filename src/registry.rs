@@ -0,0 +1,121 @@
+use crate::*;
+use crate::error::Error;
+use crate::solution::{Answer, Result, Solution, Source};
+
+use std::time::{Duration, Instant};
+
+/// A solver for one part of a day. It takes the raw puzzle input, parses it
+/// and returns the answer.
+pub type Part = fn(&str) -> Result<Answer>;
+
+/// Parses once, then solves each of the given parts, timing every step.
+pub type Timed = fn(&str, &[u8]) -> Result<Run>;
+
+/// Finds every problem with an input, without solving anything.
+pub type Check = fn(&str) -> Vec<Error>;
+
+/// What [`Day::timed`] found, and how long it took.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<(u8, Answer, Duration)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    pub source: Source,
+    pub part1: Part,
+    pub part2: Option<Part>,
+    pub timed: Timed,
+    pub check: Check,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            title: S::TITLE,
+            source: S::SOURCE,
+            part1: part1::<S>,
+            part2: if S::PARTS >= 2 { Some(part2::<S>) } else { None },
+            timed: timed::<S>,
+            check: check::<S>,
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<Part> {
+        match part {
+            1 => Some(self.part1),
+            2 => self.part2,
+            _ => None,
+        }
+    }
+}
+
+fn part1<S: Solution>(input: &str) -> Result<Answer> {
+    Ok(S::part1(&S::parse(input)?))
+}
+
+fn part2<S: Solution>(input: &str) -> Result<Answer> {
+    Ok(S::part2(&S::parse(input)?))
+}
+
+fn timed<S: Solution>(input: &str, parts: &[u8]) -> Result<Run> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let parts = parts.iter()
+        .filter(|&&part| part == 1 || (part == 2 && S::PARTS >= 2))
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = if part == 1 { S::part1(&input) } else { S::part2(&input) };
+            (part, answer, start.elapsed())
+        })
+        .collect();
+
+    Ok(Run { parse, parts })
+}
+
+fn check<S: Solution>(input: &str) -> Vec<Error> {
+    let errors = S::check(input);
+    if errors.is_empty() {
+        S::parse(input).err().into_iter().collect()
+    } else {
+        errors
+    }
+}
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+pub static DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+    Day::new::<day13::Day13>(),
+    Day::new::<day14::Day14>(),
+    Day::new::<day15::Day15>(),
+    Day::new::<day16::Day16>(),
+    Day::new::<day17::Day17>(),
+    Day::new::<day18::Day18>(),
+    Day::new::<day19::Day19>(),
+    Day::new::<day20::Day20>(),
+    Day::new::<day21::Day21>(),
+    Day::new::<day22::Day22>(),
+    Day::new::<day23::Day23>(),
+    Day::new::<day24::Day24>(),
+    Day::new::<day25::Day25>(),
+];
@@ -0,0 +1,117 @@
+use std::fmt;
+use std::convert::TryFrom;
+
+pub use crate::error::Result;
+
+use crate::error::Error;
+
+/// The shape every day shares: parse the puzzle input once, then solve both
+/// parts from the parsed form.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+    /// How many parts are solved so far
+    const PARTS: u8 = 2;
+    const SOURCE: Source = Source::Text;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    /// Problems with the input found line by line, for days with one record
+    /// per line, so `aoc check` can report them all instead of the first.
+    /// Whatever this finds, the input still has to `parse`.
+    fn check(_input: &str) -> Vec<Error> {
+        Vec::new()
+    }
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// How a day expects its puzzle input to be given on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// A puzzle file, read from `--input <path>` or stdin
+    Text,
+    /// A single number, usually passed as an argument (day 11's serial)
+    Number,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// Comma separated, like `x,y` or `x,y,size`
+    Coords(Vec<i64>),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Coords(coords) => write!(f, "{}",
+                coords.iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Number(i64::from(n))
+                }
+            }
+        )*
+    };
+}
+
+/// Numbers too big for an `i64` are kept as their digits.
+macro_rules! answer_from_wide_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Number(n),
+                        Err(_) => Answer::Text(n.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, u32, i64);
+answer_from_wide_number!(u64, isize, usize, i128, u128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[test]
+fn answer_display() {
+    assert_eq!(Answer::from(-42_isize).to_string(), "-42");
+    assert_eq!(Answer::from("LAPFC").to_string(), "LAPFC");
+    assert_eq!(Answer::Coords(vec![90, 269, 16]).to_string(), "90,269,16");
+    assert_eq!(Answer::from(i128::from(i64::MIN) - 1).to_string(), "-9223372036854775809");
+    assert_eq!(Answer::from(u128::from(u64::MAX) * 2), Answer::Text("36893488147419103230".to_string()));
+    assert_eq!(Answer::from(7_u128), Answer::Number(7));
+}
@@ -0,0 +1,172 @@
+//! Runs every day against its inputs and checks the results against the
+//! [`answers`](crate::answers) files.
+
+use crate::answers::{self, Answers};
+use crate::budget;
+use crate::error::{Error, Result};
+use crate::registry::{self, Day};
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    /// The answer didn't match; holds what the solver gave instead
+    Fail(String),
+    /// No expected answer, or no input to check it against
+    Missing,
+    /// The input didn't parse, or the solver panicked
+    Error(String),
+    Timeout,
+}
+
+impl Status {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Status::Pass | Status::Missing)
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail(_) => "FAIL",
+            Status::Missing => "missing",
+            Status::Error(_) => "ERROR",
+            Status::Timeout => "TIMEOUT",
+        };
+
+        write!(f, "{}", status)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    /// File name of the input, or `-` for a day without any
+    pub input: String,
+    pub part: u8,
+    pub expected: Option<String>,
+    pub status: Status,
+}
+
+/// Checks every registered day over the inputs in `input_dir`.
+pub fn verify(input_dir: &Path, answers_dir: &Path, timeout: Duration) -> Result<Vec<Check>> {
+    let mut checks = Vec::new();
+
+    for day in registry::DAYS {
+        checks.extend(verify_day(day, input_dir, answers_dir, timeout)?);
+    }
+
+    Ok(checks)
+}
+
+pub fn verify_day(day: &Day, input_dir: &Path, answers_dir: &Path, timeout: Duration) -> Result<Vec<Check>> {
+    let answers = answers::load(answers_dir, day.day)?;
+    let inputs = inputs(day.day, input_dir, &answers)?;
+    let mut checks = Vec::new();
+
+    if inputs.is_empty() {
+        for part in parts(day) {
+            checks.push(Check {
+                day: day.day,
+                input: "-".to_string(),
+                part,
+                expected: None,
+                status: Status::Missing,
+            });
+        }
+    }
+
+    for input in inputs {
+        let text = fs::read_to_string(input_dir.join(&input));
+        let expected = answers.get(&input).cloned().unwrap_or_default();
+
+        for part in parts(day) {
+            let expected = expected.part(part).map(str::to_string);
+            let status = match (&text, &expected, day.part(part)) {
+                (Err(e), _, _) => Status::Error(e.to_string()),
+                (Ok(text), Some(expected), Some(solve)) => check(solve, text, expected, timeout),
+                _ => Status::Missing,
+            };
+
+            checks.push(Check { day: day.day, input: input.clone(), part, expected, status });
+        }
+    }
+
+    Ok(checks)
+}
+
+fn parts(day: &Day) -> Vec<u8> {
+    (1..=2).filter(|&p| day.part(p).is_some()).collect()
+}
+
+/// The files for a day: everything in `input_dir` named `dayNN...`, plus any
+/// other file its answers name, like day 13's `example1.txt`.
+fn inputs(day: u8, input_dir: &Path, answers: &Answers) -> Result<Vec<String>> {
+    let prefix = format!("day{:02}", day);
+    let mut inputs: Vec<String> = answers.keys().cloned().collect();
+
+    let entries = match fs::read_dir(input_dir) {
+        Ok(entries) => entries,
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(inputs),
+        Err(e) => return Err(e.into()),
+    };
+
+    for entry in entries {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        let is_day = name.strip_prefix(&prefix)
+            .is_some_and(|rest| !rest.starts_with(|c: char| c.is_ascii_digit()));
+        if is_day && !inputs.contains(&name) {
+            inputs.push(name);
+        }
+    }
+
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Solves on another thread, so a panic or a runaway solver only fails its
+/// own check. A solver that ignores its budget is left running.
+fn check(solve: registry::Part, input: &str, expected: &str, timeout: Duration) -> Status {
+    let (tx, rx) = mpsc::channel();
+    let input = input.to_string();
+
+    thread::spawn(move || {
+        let result = budget::limit(timeout, || solve(&input)).map(|answer| answer.to_string());
+        let _ = tx.send(result);
+    });
+
+    match rx.recv_timeout(timeout + budget::GRACE) {
+        Ok(Ok(answer)) => {
+            if same(&answer, expected) {
+                Status::Pass
+            } else {
+                Status::Fail(answer)
+            }
+        },
+        Ok(Err(Error::Timeout { .. })) | Err(mpsc::RecvTimeoutError::Timeout) => Status::Timeout,
+        Ok(Err(e)) => Status::Error(e.to_string()),
+        Err(mpsc::RecvTimeoutError::Disconnected) => Status::Error("panicked".to_string()),
+    }
+}
+
+/// Compares answers ignoring trailing whitespace, which rendered answers like
+/// day 10's message don't agree on.
+pub fn same(answer: &str, expected: &str) -> bool {
+    answer.trim_end().lines().map(str::trim_end)
+        .eq(expected.trim_end().lines().map(str::trim_end))
+}
+
+#[test]
+fn same_answers() {
+    assert!(same("# #  \n###\n", "# #\n###"));
+    assert!(!same("# #\n###", "# #\n##"));
+    assert!(same("420", "420"));
+}
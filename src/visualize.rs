@@ -0,0 +1,456 @@
+//! Watching the simulations run.
+//!
+//! Days that step a simulation forward draw a [`Frame`] after every step
+//! with [`frame`]. Nothing is drawn unless the runner is [`show`]ing the
+//! solve to a [`Backend`]: in the terminal, as plain text, or as images on
+//! disk. Like the time [`budget`](crate::budget), the backend is kept per
+//! thread, so solvers don't have to pass it around.
+
+use crate::error::Result;
+use crate::grid::{Bounds, Grid, Point};
+
+use std::cell::RefCell;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static SHOWING: RefCell<Option<Showing>> = const { RefCell::new(None) };
+}
+
+/// A backend, and the first thing that went wrong with it. After an error
+/// there's no point drawing any more frames.
+struct Showing {
+    backend: Box<dyn Backend>,
+    error: Option<io::Error>,
+}
+
+/// Stops showing frames, even if the solver panics.
+struct Restore(Option<Showing>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        SHOWING.with(|showing| *showing.borrow_mut() = self.0.take());
+    }
+}
+
+/// Runs `solve`, showing every frame it draws on `backend`. An error
+/// showing frames is only reported if the solve itself went fine.
+pub fn show<T, F: FnOnce() -> Result<T>>(backend: Box<dyn Backend>, solve: F) -> Result<T> {
+    let restore = Restore(SHOWING.with(|s| s.borrow_mut().replace(Showing { backend, error: None })));
+
+    let result = solve();
+
+    let showing = SHOWING.with(|s| s.borrow_mut().take());
+    drop(restore);
+
+    let mut showing = showing.expect("the backend being shown");
+    let finished = match showing.error.take() {
+        Some(e) => Err(e),
+        None => showing.backend.finish(),
+    };
+
+    let answer = result?;
+    finished?;
+    Ok(answer)
+}
+
+/// Whether anything is watching. Only worth asking to skip work that
+/// [`frame`] doesn't cover.
+pub fn watching() -> bool {
+    SHOWING.with(|showing| showing.borrow().as_ref().is_some_and(|s| s.error.is_none()))
+}
+
+/// Shows the frame `draw` makes, if anything is watching. It isn't called
+/// otherwise, so drawing costs nothing on a normal run.
+pub fn frame<F: FnOnce() -> Frame>(draw: F) {
+    SHOWING.with(|showing| {
+        if let Some(showing) = &mut *showing.borrow_mut() {
+            if showing.error.is_none() {
+                if let Err(e) = showing.backend.show(&draw()) {
+                    showing.error = Some(e);
+                }
+            }
+        }
+    })
+}
+
+/// One step of a simulation, as a character per cell, and a line saying
+/// which step it is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub cells: Grid<char>,
+}
+
+impl Frame {
+    pub fn new<S: Into<String>>(caption: S, cells: Grid<char>) -> Frame {
+        Frame { caption: caption.into(), cells }
+    }
+
+    /// A frame of whatever `map` displays as, one row per line.
+    pub fn draw<S: Into<String>, D: fmt::Display>(caption: S, map: &D) -> Frame {
+        let cells = Grid::from_chars(&map.to_string(), |_, c| Ok(c))
+            .expect("reading characters can't fail");
+        Frame::new(caption, cells)
+    }
+
+    /// `points` as `#`s, shrunk to fit in `width` by `height` cells if they
+    /// spread any further.
+    pub fn plot<S, I>(caption: S, points: I, width: i64, height: i64) -> Frame
+    where S: Into<String>, I: IntoIterator<Item = Point> + Clone {
+        let bounds = match Bounds::of(points.clone()) {
+            Some(bounds) => bounds,
+            None => return Frame::new(caption, Grid::new(Bounds::new(Point::default(), Point::default()), ' ')),
+        };
+
+        let scale = |extent: i64, most: i64| (extent + most - 1) / most.max(1);
+        let (sx, sy) = (scale(bounds.width(), width).max(1), scale(bounds.height(), height).max(1));
+        let size = Point::new((bounds.width() - 1) / sx, (bounds.height() - 1) / sy);
+
+        let mut cells = Grid::new(Bounds::new(Point::default(), size), ' ');
+        for p in points {
+            let offset = p - bounds.min;
+            cells[Point::new(offset.x / sx, offset.y / sy)] = '#';
+        }
+
+        Frame::new(caption, cells)
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.caption)?;
+        write!(f, "{}", self.cells)
+    }
+}
+
+/// Somewhere to show frames.
+pub trait Backend {
+    fn show(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Called once the solve is done, to write out anything held back.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Animates the frames in the terminal, on stderr so the answers on stdout
+/// stay clean.
+///
+/// Pressing enter pauses; while paused, enter shows the next frame, `p`
+/// plays on and `q` stops watching, leaving the solve to finish by itself.
+pub struct Terminal {
+    delay: Duration,
+    paused: bool,
+    quit: bool,
+    shown: Option<Instant>,
+    keys: Option<Receiver<String>>,
+}
+
+impl Terminal {
+    /// Plays `fps` frames a second, or starts paused with `step`.
+    pub fn new(fps: f64, step: bool) -> Terminal {
+        Terminal {
+            delay: Duration::from_secs_f64(1.0 / fps.max(0.01)),
+            paused: step,
+            quit: false,
+            shown: None,
+            keys: None,
+        }
+    }
+
+    /// Lines typed while the animation runs, read on another thread so
+    /// playing doesn't wait on them.
+    fn keys(&mut self) -> &Receiver<String> {
+        self.keys.get_or_insert_with(|| {
+            let (send, keys) = mpsc::channel();
+            thread::spawn(move || {
+                for line in io::stdin().lock().lines() {
+                    match line {
+                        Ok(line) if send.send(line.trim().to_string()).is_ok() => (),
+                        _ => break,
+                    }
+                }
+            });
+            keys
+        })
+    }
+
+    /// Acts on a line typed while watching. Anything but `p` or `q` pauses,
+    /// or steps on a frame if already paused.
+    fn key(&mut self, key: &str) {
+        match key {
+            "q" => self.quit = true,
+            "p" => self.paused = false,
+            _ => self.paused = true,
+        }
+    }
+}
+
+impl Backend for Terminal {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        if self.quit {
+            return Ok(());
+        }
+
+        if let Some(shown) = self.shown {
+            let next = shown + self.delay;
+            let now = Instant::now();
+            if next > now {
+                thread::sleep(next - now);
+            }
+        }
+
+        let stderr = io::stderr();
+        let mut out = stderr.lock();
+        // Clear the screen and go back to the top left
+        write!(out, "\x1b[2J\x1b[H{}", frame)?;
+        out.flush()?;
+
+        if !self.paused {
+            if let Ok(key) = self.keys().try_recv() {
+                self.key(&key);
+            }
+        }
+
+        if self.paused && !self.quit {
+            write!(out, "[enter] next  [p] play  [q] stop watching ")?;
+            out.flush()?;
+            match self.keys().recv() {
+                Ok(key) => self.key(&key),
+                // There's nothing more to read, so nothing could unpause
+                Err(_) => self.paused = false,
+            }
+        }
+
+        self.shown = Some(Instant::now());
+        Ok(())
+    }
+}
+
+/// Writes every frame out as plain text, one after the other.
+pub struct Text<W: Write>(pub W);
+
+impl<W: Write> Backend for Text<W> {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        writeln!(self.0, "{}", frame)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// How many pixels wide and high a cell is drawn in an image.
+pub const CELL: usize = 4;
+
+/// The colours cells are drawn in, as RGB. See [`colour`].
+pub const PALETTE: [u8; 24] = [
+    0x10, 0x10, 0x18, // nothing
+    0xcc, 0xcc, 0xcc, // walls, stars and clay
+    0x66, 0x66, 0x66, // track
+    0xff, 0xcc, 0x00, // carts
+    0x33, 0xcc, 0x33, // elves and trees
+    0xdd, 0x33, 0x33, // goblins and crashes
+    0x33, 0x77, 0xff, // water
+    0xff, 0xff, 0xff, // anything else
+];
+
+/// Which colour in the [`PALETTE`] a cell is drawn in.
+pub fn colour(c: char) -> u8 {
+    match c {
+        ' ' | '.' => 0,
+        '#' => 1,
+        '|' | '-' | '/' | '\\' | '+' => 2,
+        '<' | '>' | '^' | 'v' => 3,
+        'E' => 4,
+        'G' | 'X' => 5,
+        '~' => 6,
+        _ => 7,
+    }
+}
+
+/// The frame's cells as palette indices, `CELL` pixels to a side, on an
+/// image `width` by `height` pixels. A frame that doesn't fill the image is
+/// padded with nothing, and one that's too big is cut off.
+fn pixels(frame: &Frame, width: usize, height: usize) -> Vec<u8> {
+    let mut pixels = vec![0; width * height];
+    for (y, row) in frame.cells.rows().enumerate().take(height / CELL) {
+        for (x, &c) in row.iter().enumerate().take(width / CELL) {
+            let colour = colour(c);
+            for py in y * CELL..(y + 1) * CELL {
+                pixels[py * width + x * CELL..py * width + (x + 1) * CELL].fill(colour);
+            }
+        }
+    }
+
+    pixels
+}
+
+/// The size of image a frame needs, as big as a GIF can be at most.
+fn size(frame: &Frame) -> (u16, u16) {
+    let side = |cells: usize| (cells * CELL).clamp(CELL, usize::from(u16::MAX) / CELL * CELL) as u16;
+    (side(frame.cells.width()), side(frame.cells.height()))
+}
+
+/// Writes the frames out as one animated GIF, as big as the first frame.
+pub struct Gif {
+    path: PathBuf,
+    delay: u16,
+    encoder: Option<gif::Encoder<BufWriter<File>>>,
+    size: (u16, u16),
+}
+
+impl Gif {
+    /// The GIF goes in `path`, playing `fps` frames a second.
+    pub fn new<P: Into<PathBuf>>(path: P, fps: f64) -> Gif {
+        Gif {
+            path: path.into(),
+            // GIFs count delays in hundredths of a second
+            delay: (100.0 / fps.max(0.01)).round().clamp(1.0, f64::from(u16::MAX)) as u16,
+            encoder: None,
+            size: (0, 0),
+        }
+    }
+}
+
+impl Backend for Gif {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        if self.encoder.is_none() {
+            self.size = size(frame);
+            let file = BufWriter::new(File::create(&self.path)?);
+            let mut encoder = gif::Encoder::new(file, self.size.0, self.size.1, &PALETTE).map_err(io::Error::other)?;
+            encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+            self.encoder = Some(encoder);
+        }
+
+        let (width, height) = self.size;
+        let pixels = pixels(frame, usize::from(width), usize::from(height));
+        let mut image = gif::Frame::from_indexed_pixels(width, height, pixels, None);
+        image.delay = self.delay;
+
+        let encoder = self.encoder.as_mut().expect("the encoder just made");
+        encoder.write_frame(&image).map_err(io::Error::other)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        match self.encoder.take() {
+            Some(encoder) => encoder.into_inner().map_err(io::Error::other)?.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Writes every frame out as a numbered PNG in a directory, to put together
+/// with whatever tools you like.
+pub struct Pngs {
+    dir: PathBuf,
+    count: usize,
+}
+
+impl Pngs {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Pngs {
+        Pngs { dir: dir.into(), count: 0 }
+    }
+}
+
+impl Backend for Pngs {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        if self.count == 0 {
+            fs::create_dir_all(&self.dir)?;
+        }
+        self.count += 1;
+
+        let (width, height) = size(frame);
+        let path = self.dir.join(format!("frame{:05}.png", self.count));
+        let mut encoder = png::Encoder::new(BufWriter::new(File::create(path)?), u32::from(width), u32::from(height));
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(&PALETTE[..]);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&pixels(frame, usize::from(width), usize::from(height)))?;
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::rc::Rc;
+
+    /// Keeps the frames where the test can see them.
+    struct Record(Rc<RefCell<Vec<Frame>>>);
+
+    impl Backend for Record {
+        fn show(&mut self, frame: &Frame) -> io::Result<()> {
+            self.0.borrow_mut().push(frame.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn frames_are_only_drawn_when_watched() {
+        let mut drawn = 0;
+        frame(|| {
+            drawn += 1;
+            Frame::draw("step 0", &"#.")
+        });
+        assert_eq!(drawn, 0);
+
+        let frames = Rc::new(RefCell::new(Vec::new()));
+        let answer = show(Box::new(Record(frames.clone())), || {
+            assert!(watching());
+            for step in 1..=3 {
+                frame(|| Frame::draw(format!("step {}", step), &"#.\n.#"));
+            }
+            Ok(42)
+        });
+
+        assert_eq!(answer.unwrap(), 42);
+        assert!(!watching());
+        assert_eq!(frames.borrow().len(), 3);
+        assert_eq!(frames.borrow()[2].to_string(), "step 3\n#.\n.#\n");
+    }
+
+    #[test]
+    fn plots_shrink_to_fit() {
+        let points = [Point::new(-100, 0), Point::new(99, 9), Point::new(0, 5)];
+        let frame = Frame::plot("", points.iter().copied(), 20, 10);
+        assert_eq!((frame.cells.width(), frame.cells.height()), (20, 10));
+        assert_eq!(frame.cells[Point::new(0, 0)], '#');
+        assert_eq!(frame.cells[Point::new(19, 9)], '#');
+        assert_eq!(frame.cells[Point::new(10, 5)], '#');
+
+        let close = Frame::plot("", vec![Point::new(3, 3), Point::new(4, 3)], 20, 10);
+        assert_eq!(close.cells.to_string(), "##\n");
+    }
+
+    #[test]
+    fn writes_images() {
+        let dir = std::env::temp_dir().join(format!("aoc-visualize-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let frames = [Frame::draw("1", &"#E\nG."), Frame::draw("2", &".E\n#G")];
+        let gif = dir.join("test.gif");
+        let backends: Vec<Box<dyn Backend>> = vec![Box::new(Gif::new(&gif, 10.0)), Box::new(Pngs::new(dir.join("frames")))];
+        for mut backend in backends {
+            for frame in &frames {
+                backend.show(frame).unwrap();
+            }
+            backend.finish().unwrap();
+        }
+
+        assert!(fs::read(&gif).unwrap().starts_with(b"GIF89a"));
+        assert!(fs::read(dir.join("frames/frame00002.png")).unwrap().starts_with(b"\x89PNG"));
+        assert!(!dir.join("frames/frame00003.png").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,295 @@
+//! Every day's answers over the puzzle-statement examples and the real inputs
+//! in `input/`, so a refactor can't quietly change a result.
+
+use advent_of_code_2018::*;
+use advent_of_code_2018::error::Result;
+use advent_of_code_2018::solution::Solution;
+
+use std::path::Path;
+
+/// Parses once and solves both parts, as the runner would print them.
+fn solve<S: Solution>(input: &str) -> Result<(String, String)> {
+    let input = S::parse(input)?;
+    Ok((S::part1(&input).to_string(), S::part2(&input).to_string()))
+}
+
+fn part1<S: Solution>(input: &str) -> Result<String> {
+    Ok(S::part1(&S::parse(input)?).to_string())
+}
+
+#[test]
+fn day01() -> Result<()> {
+    assert_eq!(solve::<day01::Day01>("+1\n-2\n+3\n+1\n")?, ("3".into(), "2".into()));
+    assert_eq!(part1::<day01::Day01>("+1\n+1\n+1\n")?, "3");
+    assert_eq!(solve::<day01::Day01>("+3\n+3\n+4\n-2\n-4\n")?.1, "10");
+    assert_eq!(solve::<day01::Day01>("-6\n+3\n+8\n+5\n-6\n")?.1, "5");
+    assert_eq!(solve::<day01::Day01>("+7\n+7\n-2\n-7\n-4\n")?.1, "14");
+
+    assert_eq!(solve::<day01::Day01>(include_str!("../input/day01.txt"))?, ("420".into(), "227".into()));
+    Ok(())
+}
+
+#[test]
+fn day02() -> Result<()> {
+    let example = "abcdef\nbababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab\n";
+    assert_eq!(part1::<day02::Day02>(example)?, "12");
+    let example = "abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz\n";
+    assert_eq!(solve::<day02::Day02>(example)?.1, "fgij");
+
+    assert_eq!(
+        solve::<day02::Day02>(include_str!("../input/day02.txt"))?,
+        ("4712".into(), "lufjygedpvfbhftxiwnaorzmq".into())
+    );
+    Ok(())
+}
+
+#[test]
+fn day03() -> Result<()> {
+    let example = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n";
+    assert_eq!(solve::<day03::Day03>(example)?, ("4".into(), "3".into()));
+
+    assert_eq!(solve::<day03::Day03>(include_str!("../input/day03.txt"))?, ("117505".into(), "1254".into()));
+    Ok(())
+}
+
+const DAY04_EXAMPLE: &str = "\
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up
+";
+
+#[test]
+fn day04() -> Result<()> {
+    assert_eq!(solve::<day04::Day04>(DAY04_EXAMPLE)?, ("240".into(), "4455".into()));
+
+    assert_eq!(solve::<day04::Day04>(include_str!("../input/day04.txt"))?, ("125444".into(), "18325".into()));
+    Ok(())
+}
+
+#[test]
+fn day05() -> Result<()> {
+    assert_eq!(solve::<day05::Day05>("dabAcCaCBAcCcaDA")?, ("10".into(), "4".into()));
+
+    assert_eq!(solve::<day05::Day05>(include_str!("../input/day05.txt"))?, ("11298".into(), "5148".into()));
+    Ok(())
+}
+
+#[test]
+fn day06() -> Result<()> {
+    // Part 2 of the example uses a smaller region than the real puzzle
+    let example = "1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9\n";
+    assert_eq!(part1::<day06::Day06>(example)?, "17");
+
+    assert_eq!(part1::<day06::Day06>(include_str!("../input/day06.txt"))?, "4771");
+    Ok(())
+}
+
+#[test]
+#[ignore = "part 2 counts the areas of the coordinates instead of the points in the region"]
+fn day06_part2() -> Result<()> {
+    assert_eq!(solve::<day06::Day06>(include_str!("../input/day06.txt"))?.1, "39149");
+    Ok(())
+}
+
+#[test]
+#[ignore = "part 1 doesn't pick the first available step alphabetically"]
+fn day07() -> Result<()> {
+    let example = "\
+Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.
+";
+    assert_eq!(part1::<day07::Day07>(example)?, "CABDFE");
+
+    assert_eq!(part1::<day07::Day07>(include_str!("../input/day07.txt"))?, "LAPFCRGHVZOTKWENBXIMSUDJQY");
+    Ok(())
+}
+
+#[test]
+fn day08() -> Result<()> {
+    assert_eq!(solve::<day08::Day08>(include_str!("../input/day08_example.txt"))?, ("138".into(), "66".into()));
+    Ok(())
+}
+
+#[test]
+fn day09() -> Result<()> {
+    assert_eq!(part1::<day09::Day09>("9 players; last marble is worth 25 points")?, "32");
+    assert_eq!(part1::<day09::Day09>(include_str!("../input/day09_example.txt"))?, "8317");
+    Ok(())
+}
+
+#[test]
+fn day10() -> Result<()> {
+    let (message, seconds) = solve::<day10::Day10>(include_str!("../input/day10.txt"))?;
+    let expected = include_str!("../answers/day10.toml")
+        .split("'''")
+        .nth(1)
+        .expect("day 10's answer is a ''' literal");
+
+    assert!(verify::same(&message, expected.trim_start_matches('\n')), "{}", message);
+    assert_eq!(seconds, "10105");
+    Ok(())
+}
+
+#[test]
+fn day11() -> Result<()> {
+    assert_eq!(part1::<day11::Day11>("18")?, "33,45");
+    assert_eq!(part1::<day11::Day11>("42")?, "21,61");
+    Ok(())
+}
+
+#[test]
+fn day11_part2() -> Result<()> {
+    assert_eq!(solve::<day11::Day11>("18")?.1, "90,269,16");
+    assert_eq!(solve::<day11::Day11>("42")?.1, "232,251,12");
+    Ok(())
+}
+
+#[test]
+fn day12() -> Result<()> {
+    assert_eq!(part1::<day12::Day12>(include_str!("../input/day12_example.txt"))?, "325");
+
+    assert_eq!(part1::<day12::Day12>(include_str!("../input/day12.txt"))?, "2166");
+    Ok(())
+}
+
+#[test]
+#[ignore = "part 2 simulates all fifty billion generations"]
+fn day12_part2() -> Result<()> {
+    assert_eq!(solve::<day12::Day12>(include_str!("../input/day12.txt"))?.1, "2100000000061");
+    Ok(())
+}
+
+#[test]
+fn day13() -> Result<()> {
+    // Every cart in the first example crashes, so it has no part 2
+    assert_eq!(part1::<day13::Day13>(include_str!("../input/day13_example.txt"))?, "7,3");
+    assert_eq!(solve::<day13::Day13>(include_str!("../input/example1.txt"))?, ("7,2".into(), "7,4".into()));
+    assert_eq!(solve::<day13::Day13>(include_str!("../input/example2.txt"))?, ("2,0".into(), "6,4".into()));
+
+    assert_eq!(solve::<day13::Day13>(include_str!("../input/day13.txt"))?, ("117,62".into(), "69,67".into()));
+    Ok(())
+}
+
+#[test]
+fn day14() -> Result<()> {
+    assert_eq!(part1::<day14::Day14>("9")?, "5158916779");
+    assert_eq!(part1::<day14::Day14>("5")?, "0124515891");
+    assert_eq!(part1::<day14::Day14>("18")?, "9251071085");
+    assert_eq!(part1::<day14::Day14>("2018")?, "5941429882");
+
+    assert_eq!(solve::<day14::Day14>("51589")?.1, "9");
+    assert_eq!(solve::<day14::Day14>("01245")?.1, "5");
+    assert_eq!(solve::<day14::Day14>("92510")?.1, "18");
+    assert_eq!(solve::<day14::Day14>("59414")?.1, "2018");
+    Ok(())
+}
+
+#[test]
+fn day15() -> Result<()> {
+    assert_eq!(solve::<day15::Day15>(include_str!("../input/day15_example.txt"))?, ("27730".into(), "4988".into()));
+
+    let examples = [
+        ("#######\n#G..#E#\n#E#E.E#\n#G.##.#\n#...#E#\n#...E.#\n#######\n", "36334", None),
+        ("#######\n#E..EG#\n#.#G.E#\n#E.##E#\n#G..#.#\n#..E#.#\n#######\n", "39514", Some("31284")),
+        ("#######\n#E.G#.#\n#.#G..#\n#G.#.G#\n#G..#.#\n#...E.#\n#######\n", "27755", Some("3478")),
+        ("#######\n#.E...#\n#.#..G#\n#.###.#\n#E#G#G#\n#...#G#\n#######\n", "28944", Some("6474")),
+        ("#########\n#G......#\n#.E.#...#\n#..##..G#\n#...##..#\n#...#...#\n#.G...G.#\n#.....G.#\n#########\n", "18740", Some("1140")),
+    ];
+    for (map, outcome, boosted) in examples.iter() {
+        let (part1, part2) = solve::<day15::Day15>(map)?;
+        assert_eq!(part1, *outcome, "{}", map);
+        if let Some(boosted) = boosted {
+            assert_eq!(part2, *boosted, "{}", map);
+        }
+    }
+
+    assert_eq!(solve::<day15::Day15>(include_str!("../input/day15.txt"))?, ("189910".into(), "57820".into()));
+    Ok(())
+}
+
+#[test]
+fn day16() -> Result<()> {
+    assert_eq!(part1::<day16::Day16>(include_str!("../input/day16_example.txt"))?, "1");
+    Ok(())
+}
+
+#[test]
+fn day17() -> Result<()> {
+    assert_eq!(solve::<day17::Day17>(include_str!("../input/day17_example.txt"))?, ("57".into(), "29".into()));
+    Ok(())
+}
+
+#[test]
+fn day18() -> Result<()> {
+    assert_eq!(part1::<day18::Day18>(include_str!("../input/day18_example.txt"))?, "1147");
+    Ok(())
+}
+
+#[test]
+fn day19() -> Result<()> {
+    assert_eq!(part1::<day19::Day19>(include_str!("../input/day19_example.txt"))?, "6");
+    Ok(())
+}
+
+#[test]
+fn day20() -> Result<()> {
+    assert_eq!(part1::<day20::Day20>("^WNE$")?, "3");
+    assert_eq!(part1::<day20::Day20>("^ENWWW(NEEE|SSE(EE|N))$")?, "10");
+    assert_eq!(part1::<day20::Day20>("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$")?, "18");
+    assert_eq!(part1::<day20::Day20>(include_str!("../input/day20_example.txt"))?, "31");
+    Ok(())
+}
+
+#[test]
+fn day22() -> Result<()> {
+    assert_eq!(solve::<day22::Day22>(include_str!("../input/day22_example.txt"))?, ("114".into(), "45".into()));
+    Ok(())
+}
+
+#[test]
+fn day23() -> Result<()> {
+    assert_eq!(part1::<day23::Day23>(include_str!("../input/day23_example.txt"))?, "7");
+    assert_eq!(solve::<day23::Day23>(include_str!("../input/day23_example2.txt"))?.1, "36");
+    Ok(())
+}
+
+#[test]
+fn day24() -> Result<()> {
+    assert_eq!(solve::<day24::Day24>(include_str!("../input/day24_example.txt"))?, ("5216".into(), "51".into()));
+    Ok(())
+}
+
+#[test]
+fn day25() -> Result<()> {
+    assert_eq!(part1::<day25::Day25>("0,0,0,0\n3,0,0,0\n0,3,0,0\n0,0,3,0\n0,0,0,3\n0,0,0,6\n9,0,0,0\n12,0,0,0\n")?, "2");
+    assert_eq!(part1::<day25::Day25>(include_str!("../input/day25_example.txt"))?, "8");
+    Ok(())
+}
+
+#[test]
+fn answers_files() -> Result<()> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("answers");
+    for day in registry::DAYS {
+        answers::load(&dir, day.day)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,145 @@
+//! Whatever a type displays as has to parse back into the same value, for
+//! every type with both `Display` and `FromStr`.
+
+use advent_of_code_2018::*;
+use advent_of_code_2018::grid::{Bounds, Grid, Point};
+
+use chrono::NaiveDate;
+use proptest::prelude::*;
+
+use std::str::FromStr;
+
+fn pot() -> impl Strategy<Value = day12::Pot> {
+    any::<bool>().prop_map(|plant| day12::Pot(if plant { Some(day12::Plant) } else { None }))
+}
+
+fn config() -> impl Strategy<Value = day12::Config> {
+    [pot(), pot(), pot(), pot(), pot()].prop_map(day12::Config)
+}
+
+fn state() -> impl Strategy<Value = day12::State> {
+    prop::collection::vec(pot(), 1..60).prop_map(|pots| day12::State {
+        pots: pots.into_iter().enumerate().map(|(i, pot)| (i as i32, pot)).collect(),
+    })
+}
+
+/// Carts only start out on straight track that runs their way, and always
+/// turn left first.
+fn position() -> impl Strategy<Value = day13::Position> {
+    use day13::{Cart, Feature::*, Heading::*, Orientation::*, Rotation::*, Turn::Left};
+
+    let cart = |heading| Some(Cart { heading, next_turn: Left });
+
+    prop_oneof![
+        Just((Track(Horizontal), None)),
+        Just((Track(Vertical), None)),
+        Just((Intersection, None)),
+        Just((Curve(Clockwise), None)),
+        Just((Curve(CounterClockwise), None)),
+        Just((Empty, None)),
+        Just((Track(Horizontal), cart(East))),
+        Just((Track(Horizontal), cart(West))),
+        Just((Track(Vertical), cart(North))),
+        Just((Track(Vertical), cart(South))),
+    ]
+    .prop_map(|(feature, cart)| day13::Position { feature, cart })
+}
+
+fn tracks() -> impl Strategy<Value = day13::Map> {
+    (1..12i64, 1..12i64)
+        .prop_flat_map(|(width, height)| {
+            prop::collection::vec(position(), (width * height) as usize)
+                .prop_map(move |positions| {
+                    let bounds = Bounds::new(Point::default(), Point::new(width - 1, height - 1));
+                    let mut grid = Grid::new(bounds, day13::Position::default());
+                    for (i, position) in positions.into_iter().enumerate() {
+                        grid[Point::new(i as i64 % width, i as i64 / width)] = position;
+                    }
+                    day13::Map(grid)
+                })
+        })
+}
+
+fn claim() -> impl Strategy<Value = day03::Claim> {
+    (0..2000usize, 0..1000i64, 0..1000i64, 1..100i64, 1..100i64).prop_map(|(id, x, y, w, h)| {
+        day03::Claim { id, rect: Bounds::new(Point::new(x, y), Point::new(x + w - 1, y + h - 1)) }
+    })
+}
+
+fn record() -> impl Strategy<Value = day04::Record> {
+    use day04::Event;
+
+    let event = prop_oneof![
+        (0..5000usize).prop_map(Event::BeginsShift),
+        Just(Event::FallsAsleep),
+        Just(Event::WakesUp),
+    ];
+
+    (1..=12u32, 1..=28u32, 0..24u32, 0..60u32, event).prop_map(|(month, day, hour, minute, event)| {
+        let time = NaiveDate::from_ymd_opt(1518, month, day)
+            .and_then(|date| date.and_hms_opt(hour, minute, 0))
+            .expect("a valid date");
+        day04::Record { time, event }
+    })
+}
+
+fn star() -> impl Strategy<Value = day10::Point> {
+    let coord = -60_000..60_000i64;
+    let speed = -6..=6i64;
+
+    (coord.clone(), coord, speed.clone(), speed).prop_map(|(px, py, vx, vy)| day10::Point {
+        position: Point::new(px, py),
+        velocity: Point::new(vx, vy),
+    })
+}
+
+proptest! {
+    #[test]
+    fn day12_pots(pot in pot()) {
+        prop_assert_eq!(day12::Pot::from_str(&pot.to_string())?, pot);
+    }
+
+    #[test]
+    fn day12_states(state in state()) {
+        prop_assert_eq!(day12::State::from_str(&state.to_string())?, state);
+    }
+
+    #[test]
+    fn day12_rules(config in config(), result in pot()) {
+        let rule = day12::Rule { config, result };
+        prop_assert_eq!(day12::Rule::from_str(&rule.to_string())?, rule);
+    }
+
+    #[test]
+    fn day13_features(position in position()) {
+        let feature = position.feature;
+        prop_assert_eq!(day13::Feature::from_str(&feature.to_string())?, feature);
+    }
+
+    #[test]
+    fn day13_maps(map in tracks()) {
+        prop_assume!(map.cart_count() >= 2);
+        prop_assert_eq!(day13::Map::from_input(&map.to_string())?, map);
+    }
+
+    #[test]
+    fn day03_claims(claim in claim()) {
+        prop_assert_eq!(day03::Claim::from_str(&claim.to_string())?, claim);
+    }
+
+    #[test]
+    fn day04_records(record in record()) {
+        prop_assert_eq!(day04::Record::from_str(&record.to_string())?, record);
+    }
+
+    #[test]
+    fn day06_coords(x in -1000..1000i64, y in -1000..1000i64) {
+        let coord = Point::new(x, y);
+        prop_assert_eq!(day06::coord(&coord.to_string())?, coord);
+    }
+
+    #[test]
+    fn day10_points(star in star()) {
+        prop_assert_eq!(day10::Point::from_str(&star.to_string())?, star);
+    }
+}